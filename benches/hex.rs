@@ -0,0 +1,59 @@
+//! Compare converting bytes into hexadecimal with the formatting machinery, the lookup table and SIMD
+//!
+//! Run with `cargo bench --bench hex`, add `--features simd` to benchmark the SIMD encoders.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use i2u::prelude::fmt::*;
+
+fn per_byte(c: &mut Criterion) {
+    let bytes: Vec<u8> = (0..=255).collect();
+    let mut group = c.benchmark_group("per_byte");
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+    group.bench_function("format", |b| {
+        b.iter(|| {
+            black_box(&bytes)
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("lower_hex_zeropad", |b| {
+        b.iter(|| {
+            black_box(&bytes)
+                .iter()
+                .map(lower_hex_zeropad::<2, _>)
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("hex_byte", |b| {
+        b.iter(|| black_box(&bytes).iter().map(hex_byte).collect::<Vec<_>>())
+    });
+    group.finish();
+}
+
+fn byte_slice(c: &mut Criterion) {
+    let mut group = c.benchmark_group("byte_slice");
+    for len in [64, 4096, 1 << 20] {
+        let bytes: Vec<u8> = (0..len).map(|i| (i * 31 + 7) as u8).collect();
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("format", len), &bytes, |b, bytes| {
+            b.iter(|| {
+                bytes
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect::<String>()
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("hex_string_ct", len),
+            &bytes,
+            |b, bytes| b.iter(|| hex_string_ct(bytes)),
+        );
+        group.bench_with_input(BenchmarkId::new("hex_string", len), &bytes, |b, bytes| {
+            b.iter(|| hex_string(bytes))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, per_byte, byte_slice);
+criterion_main!(benches);
@@ -0,0 +1,759 @@
+//! Provides functions to align collections of formatted values to a common width
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator into Strings that are all padded to the width of the longest one,
+//! without calculating the maximum width myself?
+//!
+//! How do I pad or truncate a String to a fixed width?
+//!
+//! How do I lay out many short items in as many columns as fit the terminal, like `ls`?
+//!
+//! How do I count how often each value occurs and show the counts and percentages as a table?
+//!
+//! How do I pivot (row, column, value) triples into a table, e.g.: sales by region and month?
+//!
+//! How do I draw spans, e.g.: from a trace or build log, as a Gantt chart of bars on a shared time axis?
+//!
+//! Widths are measured in chars, enable the `unicode-width` feature to measure the columns
+//! the text occupies in a terminal, so CJK and emoji chars count as 2 columns.
+//!
+//! ## Use
+//!
+//! * format_aligned(iter, to_string)
+//! * format_aligned(iter, binary)
+//! * align_column(iter, Alignment::Left)
+//! * iter.map(pad_right::<10, _>)
+//! * iter.map(truncate_ellipsis::<20, _>)
+//! * matrix(rows)
+//! * columns(text.lines(), "\t")
+//! * flow_columns(file_names, 80)
+//! * frequency_table(iter)
+//! * crosstab(iter_of_row_column_value, Aggregation::Sum)
+//! * timeline(iter_of_label_start_end, 40)
+//!
+use crate::cast::ToF64;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// Where the text is placed within the padded width
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Alignment {
+    /// Pad with trailing spaces, equivalent to **format!("{:<width$}",s)**
+    #[default]
+    Left,
+    /// Pad with leading spaces, equivalent to **format!("{:>width$}",s)**
+    Right,
+    /// Pad on both sides, any odd space goes on the right, equivalent to **format!("{:^width$}",s)**
+    Center,
+}
+
+/// The number of columns the String occupies when displayed
+#[cfg(not(feature = "unicode-width"))]
+pub(crate) fn display_width(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// The number of columns the String occupies when displayed
+#[cfg(feature = "unicode-width")]
+pub(crate) fn display_width(s: &str) -> usize {
+    unicode_width::UnicodeWidthStr::width(s)
+}
+
+/// The number of columns the char occupies when displayed
+#[cfg(not(feature = "unicode-width"))]
+pub(crate) fn char_width(_c: char) -> usize {
+    1
+}
+
+/// The number of columns the char occupies when displayed
+#[cfg(feature = "unicode-width")]
+pub(crate) fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// Pad the String with spaces to width columns according to alignment
+pub(crate) fn pad_to(s: &str, width: usize, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(display_width(s));
+    let (left, right) = match alignment {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    let mut padded = String::with_capacity(s.len() + padding);
+    padded.extend(std::iter::repeat_n(' ', left));
+    padded.push_str(s);
+    padded.extend(std::iter::repeat_n(' ', right));
+    padded
+}
+
+/// Truncate the String to at most width columns, replacing the end with … if it is too wide
+pub(crate) fn truncate_to(s: &str, width: usize) -> String {
+    if display_width(s) <= width {
+        return s.to_string();
+    }
+    let mut truncated = String::with_capacity(width.min(s.len()) + '…'.len_utf8());
+    let mut used = 0;
+    for c in s.chars() {
+        used += char_width(c);
+        if used >= width {
+            break;
+        }
+        truncated.push(c);
+    }
+    if width > 0 {
+        truncated.push('…');
+    }
+    truncated
+}
+
+/// Wrap each line of the String at spaces so no line is wider than width columns,
+/// words wider than width are split, existing new lines are kept
+#[cfg(feature = "terminal")]
+pub(crate) fn wrap_to(s: &str, width: usize) -> String {
+    let width = width.max(1);
+    let mut wrapped = String::with_capacity(s.len() + s.len() / width);
+    for (index, line) in s.lines().enumerate() {
+        if index > 0 {
+            wrapped.push('\n');
+        }
+        let mut used = 0;
+        for word in line.split(' ').filter(|word| !word.is_empty()) {
+            let word_width = display_width(word);
+            if used > 0 && used + 1 + word_width <= width {
+                wrapped.push(' ');
+                used += 1;
+            } else if used > 0 {
+                wrapped.push('\n');
+                used = 0;
+            }
+            for c in word.chars() {
+                let c_width = char_width(c);
+                if used > 0 && used + c_width > width {
+                    wrapped.push('\n');
+                    used = 0;
+                }
+                wrapped.push(c);
+                used += c_width;
+            }
+        }
+    }
+    wrapped
+}
+
+/// Pad a String or &str with leading spaces to W columns, i.e.: right align it
+///
+/// Use .map(pad_left::<10, _>) // 10 in this case is the width
+///
+/// short for **format!("{:>10}",s)**, but with the `unicode-width` feature wide chars are counted as 2 columns
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["a", "bb", "longer"];
+/// let vec_to_strings: Vec<_> = vec.iter().map(pad_left::<4, _>).collect();
+/// let expected = vec!["   a", "  bb", "longer"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn pad_left<const W: usize, S: AsRef<str>>(s: S) -> String {
+    pad_to(s.as_ref(), W, Alignment::Right)
+}
+
+/// Pad a String or &str with trailing spaces to W columns, i.e.: left align it
+///
+/// Use .map(pad_right::<10, _>) // 10 in this case is the width
+///
+/// short for **format!("{:<10}",s)**, but with the `unicode-width` feature wide chars are counted as 2 columns
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["a", "bb"];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(pad_right::<4, _>).collect();
+/// let expected = vec!["a   ", "bb  "];
+/// assert_eq!(vec_to_strings, expected);
+///
+///# #[cfg(feature = "unicode-width")]
+/// assert_eq!(pad_right::<6, _>("日本"), "日本  ");
+/// ```
+pub fn pad_right<const W: usize, S: AsRef<str>>(s: S) -> String {
+    pad_to(s.as_ref(), W, Alignment::Left)
+}
+
+/// Pad a String or &str with spaces on both sides to W columns, i.e.: center it,
+/// any odd space goes on the right
+///
+/// Use .map(pad_center::<10, _>) // 10 in this case is the width
+///
+/// short for **format!("{:^10}",s)**, but with the `unicode-width` feature wide chars are counted as 2 columns
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["a", "bb"];
+/// let vec_to_strings: Vec<_> = vec.iter().map(pad_center::<5, _>).collect();
+/// let expected = vec!["  a  ", " bb  "];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn pad_center<const W: usize, S: AsRef<str>>(s: S) -> String {
+    pad_to(s.as_ref(), W, Alignment::Center)
+}
+
+/// Truncate a String or &str to at most W columns, ending with … if it was too long
+///
+/// Use .map(truncate_ellipsis::<10, _>) // 10 in this case is the maximum width
+///
+/// With the `unicode-width` feature wide chars are counted as 2 columns,
+/// so the result never exceeds W columns in a terminal.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["short", "much too long"];
+/// let vec_to_strings: Vec<_> = vec.iter().map(truncate_ellipsis::<8, _>).collect();
+/// let expected = vec!["short", "much to…"];
+/// assert_eq!(vec_to_strings, expected);
+///
+///# #[cfg(feature = "unicode-width")]
+/// assert_eq!(truncate_ellipsis::<4, _>("日本語"), "日…");
+/// ```
+pub fn truncate_ellipsis<const W: usize, S: AsRef<str>>(s: S) -> String {
+    truncate_to(s.as_ref(), W)
+}
+
+/// Format every item of a collection and right align each String to the width of the widest one
+///
+/// A two pass helper, first every item is formatted with formatter and the maximum width is found,
+/// then every String is padded with leading spaces to that width.
+///
+/// short for
+/// ```text
+/// let strings: Vec<String> = iter.map(formatter).collect();
+/// let width = strings.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+/// strings.iter().map(|s| format!("{:>width$}", s)).collect()
+/// ```
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1, 20, 300];
+/// let aligned = format_aligned(vec.iter(), to_string);
+/// let expected = vec!["  1", " 20", "300"];
+/// assert_eq!(aligned, expected);
+///
+/// let aligned_binary = format_aligned(vec![1, 2, 5], binary);
+/// assert_eq!(aligned_binary, vec!["  1", " 10", "101"]);
+/// ```
+pub fn format_aligned<I, F>(iter: I, formatter: F) -> Vec<String>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> String,
+{
+    align_column(iter.into_iter().map(formatter), Alignment::Right)
+}
+
+/// Pad every String in a collection to the width of the longest one, left, right or center aligned
+///
+/// Useful for building ad-hoc columns of text.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let names = vec!["a", "bbb", "cc"];
+/// let left = align_column(names.iter(), Alignment::Left);
+/// assert_eq!(left, vec!["a  ", "bbb", "cc "]);
+///
+/// let right = align_column(names.iter(), Alignment::Right);
+/// assert_eq!(right, vec!["  a", "bbb", " cc"]);
+///
+/// let center = align_column(names, Alignment::Center);
+/// assert_eq!(center, vec![" a ", "bbb", "cc "]);
+/// ```
+pub fn align_column<I>(iter: I, alignment: Alignment) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let strings: Vec<I::Item> = iter.into_iter().collect();
+    let width = strings
+        .iter()
+        .map(|s| display_width(s.as_ref()))
+        .max()
+        .unwrap_or(0);
+    strings
+        .iter()
+        .map(|s| pad_to(s.as_ref(), width, alignment))
+        .collect()
+}
+
+/// Render rows of cells as lines, padding each cell to the width of the widest cell in its column
+///
+/// Rows may have different lengths, trailing spaces are removed from each line.
+pub(crate) fn render_grid(rows: &[Vec<String>], alignment: Alignment, separator: &str) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    render_grid_aligned(rows, &vec![alignment; columns], separator)
+}
+
+/// Render rows of cells as lines like [`render_grid`], with each column aligned by its entry in alignments,
+/// columns without an entry are left aligned
+pub(crate) fn render_grid_aligned(
+    rows: &[Vec<String>],
+    alignments: &[Alignment],
+    separator: &str,
+) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| display_width(cell))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    rows.iter()
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(column, (cell, &width))| {
+                    let alignment = alignments.get(column).copied().unwrap_or_default();
+                    pad_to(cell, width, alignment)
+                })
+                .collect::<Vec<_>>()
+                .join(separator);
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn to_cells<R>(rows: R) -> Vec<Vec<String>>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    <R::Item as IntoIterator>::Item: Display,
+{
+    rows.into_iter()
+        .map(|row| row.into_iter().map(|cell| cell.to_string()).collect())
+        .collect()
+}
+
+/// Convert rows of items that implement [`std::fmt::Display`] into a grid String,
+/// with each column right aligned to its widest value
+///
+/// Useful for a quick look at a numeric matrix, e.g.: a Vec of Vecs or an array of arrays.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let rows = vec![vec![1, 200, 3], vec![40, 5, -6]];
+/// let result = matrix(&rows);
+/// let expected = " 1 200  3\n40   5 -6";
+/// assert_eq!(result, expected);
+///
+/// let identity = [[1.0, 0.0], [0.0, 1.0]];
+/// assert_eq!(matrix(identity), "1 0\n0 1");
+/// ```
+pub fn matrix<R>(rows: R) -> String
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    <R::Item as IntoIterator>::Item: Display,
+{
+    render_grid(&to_cells(rows), Alignment::Right, " ")
+}
+
+/// Convert rows of items that implement [`std::fmt::Display`] into a grid String,
+/// like [`matrix`] but with a header line of column indices and a column of row indices
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let rows = vec![vec![1, 200, 3], vec![40, 5, -6]];
+/// let result = matrix_indexed(&rows);
+/// let expected = concat!(
+///     "     0   1  2\n",
+///     "0 |  1 200  3\n",
+///     "1 | 40   5 -6",
+/// );
+/// assert_eq!(result, expected);
+/// ```
+pub fn matrix_indexed<R>(rows: R) -> String
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    <R::Item as IntoIterator>::Item: Display,
+{
+    let cells = to_cells(rows);
+    let columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+    let mut grid = Vec::with_capacity(cells.len() + 1);
+    grid.push(
+        [String::new(), String::new()]
+            .into_iter()
+            .chain((0..columns).map(|column| column.to_string()))
+            .collect(),
+    );
+    grid.extend(cells.into_iter().enumerate().map(|(index, row)| {
+        [index.to_string(), String::from("|")]
+            .into_iter()
+            .chain(row)
+            .collect::<Vec<_>>()
+    }));
+    render_grid(&grid, Alignment::Right, " ")
+}
+
+/// Split each line on delimiter and pad the fields so they line up vertically, like `column -t`
+///
+/// Fields are left aligned and separated by two spaces, lines with fewer fields are allowed.
+/// Useful for prettifying TSV, CSV or log output in process.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let tsv = "name\tsize\tkind\nCargo.toml\t1024\tfile\nsrc\t4096\tdir";
+/// let result = columns(tsv.lines(), "\t");
+/// let expected = concat!(
+///     "name        size  kind\n",
+///     "Cargo.toml  1024  file\n",
+///     "src         4096  dir",
+/// );
+/// assert_eq!(result, expected);
+/// ```
+pub fn columns<I, D>(lines: I, delimiter: D) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+    D: AsRef<str>,
+{
+    let delimiter = delimiter.as_ref();
+    let rows: Vec<Vec<String>> = lines
+        .into_iter()
+        .map(|line| {
+            line.as_ref()
+                .split(delimiter)
+                .map(ToOwned::to_owned)
+                .collect()
+        })
+        .collect();
+    render_grid(&rows, Alignment::Left, "  ")
+}
+
+/// Lay out items that implement [`std::fmt::Display`] in as many columns as fit in total_width, like `ls`
+///
+/// The items fill each column top to bottom, then the next column, each column is as wide as its widest item
+/// and the columns are separated by two spaces. If an item is wider than total_width, one item is put per line.
+/// Use [`crate::term::terminal_width`] with the `terminal` feature to fill the terminal.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let files = vec!["Cargo.toml", "README.md", "benches", "src", "tests", "LICENSE", "justfile"];
+/// let expected = concat!(
+///     "Cargo.toml  src      justfile\n",
+///     "README.md   tests\n",
+///     "benches     LICENSE",
+/// );
+/// assert_eq!(flow_columns(&files, 32), expected);
+///
+/// assert_eq!(flow_columns(&files, 200), "Cargo.toml  README.md  benches  src  tests  LICENSE  justfile");
+/// assert_eq!(flow_columns(1..=3, 1), "1\n2\n3");
+///# assert_eq!(flow_columns(Vec::<u8>::new(), 80), "");
+/// ```
+pub fn flow_columns<I>(items: I, total_width: usize) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    const SEPARATOR: &str = "  ";
+    let items: Vec<String> = items.into_iter().map(|item| item.to_string()).collect();
+    let widths: Vec<usize> = items.iter().map(|item| display_width(item)).collect();
+    let count = items.len();
+    // the fewest rows, i.e. the most columns, that fit
+    let rows = (1..count)
+        .find(|&rows| {
+            let column_widths = widths
+                .chunks(rows)
+                .map(|column| column.iter().max().unwrap_or(&0));
+            let columns = count.div_ceil(rows);
+            column_widths.sum::<usize>() + SEPARATOR.len() * (columns - 1) <= total_width
+        })
+        .unwrap_or(count.max(1));
+    let mut grid = vec![Vec::new(); rows.min(count)];
+    for (index, item) in items.into_iter().enumerate() {
+        grid[index % rows].push(item);
+    }
+    render_grid(&grid, Alignment::Left, SEPARATOR)
+}
+
+/// Count how often each item occurs and convert the counts into a table of value, count and percentage,
+/// most frequent first
+///
+/// Items with the same count are in the order they first occurred. The values are left aligned and the counts
+/// and percentages right aligned, under a header line, the percentages have one decimal place.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let status_codes = vec![200, 404, 200, 500, 200, 404];
+/// let expected = concat!(
+///     "value  count  percent\n",
+///     "200        3    50.0%\n",
+///     "404        2    33.3%\n",
+///     "500        1    16.7%",
+/// );
+/// assert_eq!(frequency_table(&status_codes), expected);
+///
+/// let words = "the cat and the hat".split(' ');
+/// assert!(frequency_table(words).starts_with("value  count  percent\nthe        2    40.0%\ncat"));
+///# assert_eq!(frequency_table(Vec::<u8>::new()), "value  count  percent");
+/// ```
+pub fn frequency_table<I>(iter: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Display + Eq + Hash,
+{
+    let mut counts: HashMap<I::Item, (usize, usize)> = HashMap::new();
+    let mut total = 0;
+    for (index, item) in iter.into_iter().enumerate() {
+        counts.entry(item).or_insert((0, index)).0 += 1;
+        total += 1;
+    }
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by_key(|&(_, (count, first))| (std::cmp::Reverse(count), first));
+    let mut rows = Vec::with_capacity(counts.len() + 1);
+    rows.push(vec![
+        "value".to_string(),
+        "count".to_string(),
+        "percent".to_string(),
+    ]);
+    rows.extend(counts.into_iter().map(|(item, (count, _))| {
+        let percent = 100.0 * count as f64 / total as f64;
+        vec![
+            item.to_string(),
+            count.to_string(),
+            format!("{:.1}%", percent),
+        ]
+    }));
+    render_grid_aligned(
+        &rows,
+        &[Alignment::Left, Alignment::Right, Alignment::Right],
+        "  ",
+    )
+}
+
+/// How [`crosstab`] combines the values of triples with the same row and column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Aggregation {
+    /// The total of the values
+    #[default]
+    Sum,
+    /// The number of values, whatever they are
+    Count,
+    /// The arithmetic mean of the values
+    Mean,
+    /// The smallest value
+    Min,
+    /// The largest value
+    Max,
+    /// The first value
+    First,
+    /// The last value
+    Last,
+}
+
+impl Aggregation {
+    /// Combine the aggregate so far, of count values, with the next value
+    fn combine(self, aggregate: f64, count: usize, value: f64) -> f64 {
+        match self {
+            Aggregation::Sum | Aggregation::Mean => aggregate + value,
+            Aggregation::Count => count as f64 + 1.0,
+            Aggregation::Min => aggregate.min(value),
+            Aggregation::Max => aggregate.max(value),
+            Aggregation::First => aggregate,
+            Aggregation::Last => value,
+        }
+    }
+}
+
+/// Pivot (row, column, value) triples into a table with a header line of the columns and a column of the rows,
+/// the values of triples with the same row and column are combined by aggregation
+///
+/// The rows and columns are in the order they first occurred, cells without a value are shown as `-`,
+/// the values are right aligned and formatted like **format!("{}", f64)**.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let sales = vec![
+///     ("north", "jan", 10),
+///     ("south", "jan", 7),
+///     ("north", "feb", 12),
+///     ("north", "jan", 5),
+/// ];
+/// let expected = concat!(
+///     "       jan  feb\n",
+///     "north   15   12\n",
+///     "south    7    -",
+/// );
+/// assert_eq!(crosstab(sales.clone(), Aggregation::Sum), expected);
+///
+/// let expected = concat!(
+///     "       jan  feb\n",
+///     "north  7.5   12\n",
+///     "south    7    -",
+/// );
+/// assert_eq!(crosstab(sales, Aggregation::Mean), expected);
+///# assert_eq!(crosstab([(1, 1, 4.0), (1, 1, 2.0)], Aggregation::Count), "   1\n1  2");
+///# assert_eq!(crosstab([(1, 1, 4.0), (1, 1, 2.0)], Aggregation::Min), "   1\n1  2");
+///# assert_eq!(crosstab([(1, 1, 4.0), (1, 1, 2.0)], Aggregation::First), "   1\n1  4");
+///# assert_eq!(crosstab(Vec::<(u8, u8, u8)>::new(), Aggregation::Max), "");
+/// ```
+pub fn crosstab<I, R, C, V>(iter: I, aggregation: Aggregation) -> String
+where
+    I: IntoIterator<Item = (R, C, V)>,
+    R: Display,
+    C: Display,
+    V: ToF64,
+{
+    let (mut rows, mut columns) = (Vec::new(), Vec::new());
+    let (mut row_index, mut column_index) = (HashMap::new(), HashMap::new());
+    let mut cells: HashMap<(usize, usize), (f64, usize)> = HashMap::new();
+    for (row, column, value) in iter {
+        let index =
+            |label: String, labels: &mut Vec<String>, indices: &mut HashMap<String, usize>| {
+                *indices.entry(label).or_insert_with_key(|label| {
+                    labels.push(label.clone());
+                    labels.len() - 1
+                })
+            };
+        let row = index(row.to_string(), &mut rows, &mut row_index);
+        let column = index(column.to_string(), &mut columns, &mut column_index);
+        let value = value.to_f64();
+        cells
+            .entry((row, column))
+            .and_modify(|(aggregate, count)| {
+                *aggregate = aggregation.combine(*aggregate, *count, value);
+                *count += 1;
+            })
+            .or_insert_with(|| match aggregation {
+                Aggregation::Count => (1.0, 1),
+                _ => (value, 1),
+            });
+    }
+    if rows.is_empty() {
+        return String::new();
+    }
+    let mut grid = Vec::with_capacity(rows.len() + 1);
+    grid.push(
+        std::iter::once(String::new())
+            .chain(columns.iter().cloned())
+            .collect(),
+    );
+    for (r, row) in rows.into_iter().enumerate() {
+        let values = (0..columns.len()).map(|c| match cells.get(&(r, c)) {
+            Some(&(aggregate, count)) if aggregation == Aggregation::Mean => {
+                (aggregate / count as f64).to_string()
+            }
+            Some(&(aggregate, _)) => aggregate.to_string(),
+            None => "-".to_string(),
+        });
+        grid.push(std::iter::once(row).chain(values).collect::<Vec<_>>());
+    }
+    let mut alignments = vec![Alignment::Right; columns.len() + 1];
+    alignments[0] = Alignment::Left;
+    render_grid_aligned(&grid, &alignments, "  ")
+}
+
+/// Draw (label, start, end) spans as horizontal bars of `█` on a shared axis scaled to width columns,
+/// one span per line, followed by an axis line showing the earliest start and latest end
+///
+/// Each bar covers at least one column, so very short spans are still visible.
+/// The labels are left aligned, use the same unit for all starts and ends, e.g.: milliseconds since a trace began.
+///
+/// # Panics
+///
+/// If width is 0
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let spans = vec![("fetch", 0, 40), ("parse", 40, 60), ("render", 50, 100)];
+/// let expected = concat!(
+///     "fetch  |████████            |\n",
+///     "parse  |        ████        |\n",
+///     "render |          ██████████|\n",
+///     "       0                  100",
+/// );
+/// assert_eq!(timeline(spans, 20), expected);
+///# assert_eq!(timeline([("a", 1.5, 1.5)], 4), "a |█   |\n  1.5 1.5");
+///# assert_eq!(timeline(Vec::<(&str, u8, u8)>::new(), 4), "");
+/// ```
+pub fn timeline<I, L, S, E>(iter: I, width: usize) -> String
+where
+    I: IntoIterator<Item = (L, S, E)>,
+    L: Display,
+    S: ToF64,
+    E: ToF64,
+{
+    assert!(width > 0, "width must be greater than 0");
+    let spans: Vec<(String, f64, f64)> = iter
+        .into_iter()
+        .map(|(label, start, end)| {
+            let (start, end) = (start.to_f64(), end.to_f64());
+            (label.to_string(), start.min(end), start.max(end))
+        })
+        .collect();
+    if spans.is_empty() {
+        return String::new();
+    }
+    let min = spans
+        .iter()
+        .map(|span| span.1)
+        .fold(f64::INFINITY, f64::min);
+    let max = spans
+        .iter()
+        .map(|span| span.2)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    let column = |x: f64| {
+        if range > 0.0 {
+            (x - min) / range * width as f64
+        } else {
+            0.0
+        }
+    };
+    let label_width = spans
+        .iter()
+        .map(|span| display_width(&span.0))
+        .max()
+        .unwrap_or(0);
+    let mut lines: Vec<String> = spans
+        .iter()
+        .map(|(label, start, end)| {
+            let from = (column(*start).floor() as usize).min(width - 1);
+            let to = (column(*end).ceil() as usize).clamp(from + 1, width);
+            let mut line = pad_to(label, label_width, Alignment::Left);
+            line.push_str(" |");
+            line.extend(std::iter::repeat_n(' ', from));
+            line.extend(std::iter::repeat_n('█', to - from));
+            line.extend(std::iter::repeat_n(' ', width - to));
+            line.push('|');
+            line
+        })
+        .collect();
+    let (min, max) = (min.to_string(), max.to_string());
+    let gap = (width + 2)
+        .saturating_sub(display_width(&min) + display_width(&max))
+        .max(1);
+    lines.push(format!(
+        "{}{}{}{}",
+        " ".repeat(label_width + 1),
+        min,
+        " ".repeat(gap),
+        max
+    ));
+    lines.join("\n")
+}
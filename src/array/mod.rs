@@ -0,0 +1,185 @@
+//! Provides functions for formatting [`ndarray`] arrays in a readable, numpy style
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert an `Array1` or `Array2` into a String with:
+//!
+//! * every element aligned to the same width.
+//! * a fixed number of decimal places.
+//! * large arrays truncated with `...`.
+//!
+//! Like numpy, arrays with more than [`SUMMARY_THRESHOLD`] elements are summarized,
+//! showing the first and last [`EDGE_ITEMS`] items of each axis.
+//!
+//! ## Use
+//!
+//! * array1(&a)
+//! * arrays.iter().map(array2_precision::<2, _, _>)
+//!
+use crate::align::{display_width, pad_to, Alignment};
+use ndarray::{ArrayBase, Data, Ix1, Ix2};
+use std::fmt::Display;
+
+/// Arrays with more elements than this are summarized with `...`
+pub const SUMMARY_THRESHOLD: usize = 1000;
+
+/// The number of items shown at the start and end of each axis of a summarized array
+pub const EDGE_ITEMS: usize = 3;
+
+const ELLIPSIS: &str = "...";
+
+/// The indices shown along an axis, None is the position of the `...`
+fn axis_indices(len: usize, summarize: bool) -> Vec<Option<usize>> {
+    if summarize && len > 2 * EDGE_ITEMS {
+        (0..EDGE_ITEMS)
+            .map(Some)
+            .chain(std::iter::once(None))
+            .chain((len - EDGE_ITEMS..len).map(Some))
+            .collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+/// Join a row of formatted cells, padding all but the `...` to width
+fn join_row(cells: &[Option<String>], width: usize) -> String {
+    cells
+        .iter()
+        .map(|cell| match cell {
+            Some(cell) => pad_to(cell, width, Alignment::Right),
+            None => ELLIPSIS.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn max_width<'a>(cells: impl Iterator<Item = &'a Option<String>>) -> usize {
+    cells
+        .flatten()
+        .map(|cell| display_width(cell))
+        .max()
+        .unwrap_or(0)
+}
+
+fn format_array1<A, S, F>(a: &ArrayBase<S, Ix1>, format: F) -> String
+where
+    S: Data<Elem = A>,
+    F: Fn(&A) -> String,
+{
+    let cells: Vec<Option<String>> = axis_indices(a.len(), a.len() > SUMMARY_THRESHOLD)
+        .into_iter()
+        .map(|i| i.map(|i| format(&a[i])))
+        .collect();
+    format!("[{}]", join_row(&cells, max_width(cells.iter())))
+}
+
+fn format_array2<A, S, F>(a: &ArrayBase<S, Ix2>, format: F) -> String
+where
+    S: Data<Elem = A>,
+    F: Fn(&A) -> String,
+{
+    let (rows, columns) = a.dim();
+    let summarize = a.len() > SUMMARY_THRESHOLD;
+    let column_indices = axis_indices(columns, summarize);
+    let grid: Vec<Option<Vec<Option<String>>>> = axis_indices(rows, summarize)
+        .into_iter()
+        .map(|row| {
+            row.map(|row| {
+                column_indices
+                    .iter()
+                    .map(|column| column.map(|column| format(&a[(row, column)])))
+                    .collect()
+            })
+        })
+        .collect();
+    let width = max_width(grid.iter().flatten().flatten());
+    let lines: Vec<String> = grid
+        .iter()
+        .map(|row| match row {
+            Some(cells) => format!("[{}]", join_row(cells, width)),
+            None => ELLIPSIS.to_string(),
+        })
+        .collect();
+    format!("[{}]", lines.join("\n "))
+}
+
+/// Convert a one dimensional array of items that implement [`std::fmt::Display`] into a numpy style String
+///
+/// Use .map(array1)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use ndarray::{array, Array1};
+/// let a = array![1, 10, 100];
+/// assert_eq!(array1(&a), "[  1  10 100]");
+///
+/// let large = Array1::from_iter(0..2000);
+/// assert_eq!(array1(&large), "[   0    1    2 ... 1997 1998 1999]");
+/// ```
+pub fn array1<A: Display, S: Data<Elem = A>>(a: &ArrayBase<S, Ix1>) -> String {
+    format_array1(a, |x| x.to_string())
+}
+
+/// Convert a one dimensional array of floats into a numpy style String with P decimal places
+///
+/// Use .map(array1_precision::<2, _, _>) // 2 in this case is the precision
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use ndarray::array;
+/// let a = array![0.5, -12.25, 3.0];
+/// assert_eq!(array1_precision::<2, _, _>(&a), "[  0.50 -12.25   3.00]");
+/// ```
+pub fn array1_precision<const P: usize, A: Display, S: Data<Elem = A>>(
+    a: &ArrayBase<S, Ix1>,
+) -> String {
+    format_array1(a, |x| format!("{:.*}", P, x))
+}
+
+/// Convert a two dimensional array of items that implement [`std::fmt::Display`] into a numpy style String,
+/// one row per line with every element aligned to the same width
+///
+/// Use .map(array2)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use ndarray::{array, Array2};
+/// let a = array![[1, 2], [30, 4]];
+/// assert_eq!(array2(&a), "[[ 1  2]\n [30  4]]");
+///
+/// let large = Array2::from_shape_fn((100, 100), |(r, c)| r * 100 + c);
+/// let expected = concat!(
+///     "[[   0    1    2 ...   97   98   99]\n",
+///     " [ 100  101  102 ...  197  198  199]\n",
+///     " [ 200  201  202 ...  297  298  299]\n",
+///     " ...\n",
+///     " [9700 9701 9702 ... 9797 9798 9799]\n",
+///     " [9800 9801 9802 ... 9897 9898 9899]\n",
+///     " [9900 9901 9902 ... 9997 9998 9999]]",
+/// );
+/// assert_eq!(array2(&large), expected);
+/// ```
+pub fn array2<A: Display, S: Data<Elem = A>>(a: &ArrayBase<S, Ix2>) -> String {
+    format_array2(a, |x| x.to_string())
+}
+
+/// Convert a two dimensional array of floats into a numpy style String with P decimal places
+///
+/// Use .map(array2_precision::<2, _, _>) // 2 in this case is the precision
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use ndarray::array;
+/// let matrices = vec![array![[1.0, 0.5], [-0.25, 10.0]]];
+/// let vec_to_strings: Vec<_> = matrices.iter().map(array2_precision::<2, _, _>).collect();
+/// assert_eq!(vec_to_strings, vec!["[[ 1.00  0.50]\n [-0.25 10.00]]"]);
+/// ```
+pub fn array2_precision<const P: usize, A: Display, S: Data<Elem = A>>(
+    a: &ArrayBase<S, Ix2>,
+) -> String {
+    format_array2(a, |x| format!("{:.*}", P, x))
+}
@@ -0,0 +1,276 @@
+//! Provides traits and functions for converting numbers that work with both values and references
+//!
+//! ## The answer to the question:
+//!
+//! How do I write a function that can be used in .map() after both iter() and into_iter()
+//! when it needs the number as an f64 or i128?
+//!
+//! How do I keep only the integers in a vector or iterator that fit in a narrower type?
+//!
+//! How do I widen the numbers in a vector or iterator without `as` casts that hide whether precision is lost?
+//!
+//! [`From`] and [`Into`] are not implemented for references, so `f64::from` cannot be used after iter():
+//!
+//! ```compile_fail
+//! let vec = vec![1.5_f32, 2.5];
+//! let does_not_compile: Vec<f64> = vec.iter().map(f64::from).collect();
+//! ```
+//!
+//! The traits in this module are implemented for every primitive number, except u128 for [`ToI128`],
+//! and for references to them, so the functions in this crate that take numbers accept both.
+//!
+//! ## Use
+//!
+//! * fn f<F: ToF64>(f: F) { f.to_f64() }
+//! * fn f<I: ToI128>(i: I) { i.to_i128() }
+//! * iter.filter_map(to_u8_checked)
+//! * iter.map(to_u64)
+//! * iter.map(to_f64_lossy)
+//!
+
+/// A number that can be converted into an f64
+///
+/// Implemented for all primitive numbers and references to them. The conversion is exact for f32, f64, i8, i16,
+/// i32, u8, u16 and u32, the same types as `Into<f64>`, i64, u64, i128, u128, isize and usize are converted
+/// into the nearest f64, so integers larger than 2^53 may lose precision, as with [`Primitive::to_f64_lossy`].
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1.5_f32, 2.5];
+/// let by_ref: Vec<f64> = vec.iter().map(ToF64::to_f64).collect();
+/// let by_value: Vec<f64> = vec.into_iter().map(|f| f.to_f64()).collect();
+/// assert_eq!(by_ref, vec![1.5, 2.5]);
+/// assert_eq!(by_ref, by_value);
+///
+/// let sizes: Vec<usize> = vec![1, 10, 100];
+/// let decibels: Vec<_> = sizes.iter().map(decibel).collect();
+/// assert_eq!(decibels, vec!["0.00 dB", "20.00 dB", "40.00 dB"]);
+///# assert_eq!(u64::MAX.to_f64(), 18446744073709551615_u64 as f64);
+///# assert_eq!((-3_i128).to_f64(), -3.0);
+/// ```
+pub trait ToF64 {
+    /// The number as an f64
+    fn to_f64(&self) -> f64;
+}
+
+/// An integer that can be converted into an i128 without loss
+///
+/// Implemented for i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize and references to them.
+/// It is not implemented for u128, as values above i128::MAX cannot be represented,
+/// so a u128 would have to be silently wrapped or cause a panic.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let offsets: Vec<usize> = vec![0, 26, 4096];
+/// let vec_to_strings: Vec<_> = offsets.iter().map(signed_hex).collect();
+/// assert_eq!(vec_to_strings, vec!["0x0", "0x1A", "0x1000"]);
+///# assert_eq!((-26_isize).to_i128(), -26);
+///# assert_eq!(usize::MAX.to_i128(), usize::MAX as i128);
+/// ```
+pub trait ToI128 {
+    /// The integer as an i128
+    fn to_i128(&self) -> i128;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),*) => {
+        $(impl ToF64 for $t {
+            fn to_f64(&self) -> f64 {
+                f64::from(*self)
+            }
+        })*
+    };
+}
+
+impl_to_f64!(f32, f64, i8, i16, i32, u8, u16, u32);
+
+macro_rules! impl_to_f64_lossy {
+    ($($t:ty),*) => {
+        $(impl ToF64 for $t {
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+        })*
+    };
+}
+
+impl_to_f64_lossy!(i64, u64, i128, u128, isize, usize);
+
+impl<T: ToF64 + ?Sized> ToF64 for &T {
+    fn to_f64(&self) -> f64 {
+        (**self).to_f64()
+    }
+}
+
+macro_rules! impl_to_i128 {
+    ($($t:ty),*) => {
+        $(impl ToI128 for $t {
+            fn to_i128(&self) -> i128 {
+                i128::from(*self)
+            }
+        })*
+    };
+}
+
+impl_to_i128!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+// isize and usize are at most 64 bits on every target Rust supports, so the casts are lossless
+impl ToI128 for isize {
+    fn to_i128(&self) -> i128 {
+        *self as i128
+    }
+}
+
+impl ToI128 for usize {
+    fn to_i128(&self) -> i128 {
+        *self as i128
+    }
+}
+
+impl<T: ToI128 + ?Sized> ToI128 for &T {
+    fn to_i128(&self) -> i128 {
+        (**self).to_i128()
+    }
+}
+
+/// A primitive number, or a reference to one, that can be copied out as a value
+///
+/// Used by the cast functions so they accept both values and references,
+/// implemented for all integer and float types and references to them.
+pub trait Primitive {
+    /// The primitive type
+    type Value: Copy;
+    /// The number as a value
+    fn value(&self) -> Self::Value;
+    /// The number converted into the nearest f64, large integers may lose precision
+    fn to_f64_lossy(&self) -> f64;
+}
+
+macro_rules! impl_primitive {
+    ($($t:ty),*) => {
+        $(impl Primitive for $t {
+            type Value = $t;
+            fn value(&self) -> $t {
+                *self
+            }
+            fn to_f64_lossy(&self) -> f64 {
+                *self as f64
+            }
+        })*
+    };
+}
+
+impl_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl<T: Primitive + ?Sized> Primitive for &T {
+    type Value = T::Value;
+    fn value(&self) -> Self::Value {
+        (**self).value()
+    }
+    fn to_f64_lossy(&self) -> f64 {
+        (**self).to_f64_lossy()
+    }
+}
+
+macro_rules! checked_cast_fns {
+    ($($name:ident, $t:ty, $ok:expr, $out_of_range:expr;)*) => {
+        $(#[doc = concat!(
+            "Convert an integer into a ", stringify!($t), ", or None if it is out of range"
+        )]
+        ///
+        #[doc = concat!("Use .filter_map(", stringify!($name), ") to keep only the values that fit")]
+        ///
+        #[doc = concat!("short for **", stringify!($t), "::try_from(i).ok()**")]
+        ///
+        /// # Example
+        /// ```
+        /// use i2u::prelude::*;
+        #[doc = concat!("let vec = vec![", stringify!($ok), ", ", stringify!($out_of_range), "];")]
+        #[doc = concat!("let narrowed: Vec<", stringify!($t), "> = vec.iter().filter_map(", stringify!($name), ").collect();")]
+        #[doc = concat!("assert_eq!(narrowed, vec![", stringify!($ok), " as ", stringify!($t), "]);")]
+        #[doc = concat!("assert_eq!(", stringify!($name), "(", stringify!($out_of_range), "), None);")]
+        /// ```
+        pub fn $name<I: Primitive>(i: I) -> Option<$t>
+        where
+            $t: TryFrom<I::Value>,
+        {
+            <$t>::try_from(i.value()).ok()
+        })*
+    };
+}
+
+checked_cast_fns! {
+    to_u8_checked, u8, 255_i32, 256_i32;
+    to_u16_checked, u16, 65_535_i32, -1_i32;
+    to_u32_checked, u32, 7_i64, -7_i64;
+    to_u64_checked, u64, 7_i64, -7_i64;
+    to_u128_checked, u128, 7_i128, -7_i128;
+    to_usize_checked, usize, 7_i64, -7_i64;
+    to_i8_checked, i8, -128_i32, 128_i32;
+    to_i16_checked, i16, -32_768_i32, 32_768_i32;
+    to_i32_checked, i32, -7_i64, 2_147_483_648_i64;
+    to_i64_checked, i64, 7_u64, u64::MAX;
+    to_i128_checked, i128, 7_u128, u128::MAX;
+    to_isize_checked, isize, 7_u64, u64::MAX;
+}
+
+macro_rules! widening_cast_fns {
+    ($($name:ident, $t:ty, $input:expr, $expected:expr;)*) => {
+        $(#[doc = concat!(
+            "Convert a number into a ", stringify!($t), " without loss, only types that always fit are accepted"
+        )]
+        ///
+        #[doc = concat!("Use .map(", stringify!($name), ")")]
+        ///
+        #[doc = concat!("short for **", stringify!($t), "::from(n)** but also accepts references")]
+        ///
+        /// # Example
+        /// ```
+        /// use i2u::prelude::*;
+        #[doc = concat!("let vec = vec![", stringify!($input), "];")]
+        #[doc = concat!("let widened: Vec<", stringify!($t), "> = vec.iter().map(", stringify!($name), ").collect();")]
+        #[doc = concat!("assert_eq!(widened, vec![", stringify!($expected), "]);")]
+        /// ```
+        pub fn $name<N: Primitive>(n: N) -> $t
+        where
+            $t: From<N::Value>,
+        {
+            <$t>::from(n.value())
+        })*
+    };
+}
+
+widening_cast_fns! {
+    to_u16, u16, 255_u8, 255;
+    to_u32, u32, 65_535_u16, 65_535;
+    to_u64, u64, u32::MAX, 4_294_967_295;
+    to_u128, u128, u64::MAX, 18_446_744_073_709_551_615;
+    to_i16, i16, -128_i8, -128;
+    to_i32, i32, 65_535_u16, 65_535;
+    to_i64, i64, u32::MAX, 4_294_967_295;
+    to_i128, i128, u64::MAX, 18_446_744_073_709_551_615;
+    to_f64, f64, 16_777_217_i32, 16_777_217.0;
+}
+
+/// Convert any integer or float into the nearest f64, large integers, above 2^53, may lose precision
+///
+/// Use .map(to_f64_lossy) where the precision loss is acceptable, e.g.: to compute an average,
+/// the name documents the loss that an `as f64` cast hides.
+///
+/// short for **n as f64**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u64> = vec![1, 2, 6];
+/// let mean = vec.iter().map(to_f64_lossy).sum::<f64>() / vec.len() as f64;
+/// assert_eq!(mean, 3.0);
+///
+/// assert_eq!(to_f64_lossy(u64::MAX), 18_446_744_073_709_551_615.0);
+/// assert_eq!(to_f64_lossy(9_007_199_254_740_993_i64), 9_007_199_254_740_992.0);
+/// ```
+pub fn to_f64_lossy<N: Primitive>(n: N) -> f64 {
+    n.to_f64_lossy()
+}
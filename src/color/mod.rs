@@ -0,0 +1,308 @@
+//! Provides functions for formatting colors
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator of colors, as (r, g, b) tuples or packed u32 values, into a String of:
+//!
+//! * `#1A2B3C` upper case hexadecimal RGB.
+//! * `#1a2b3c` lower case hexadecimal RGB.
+//! * `#1A2B3CFF` hexadecimal RGBA.
+//!
+//! How do I color numbers in a terminal so the structure of a matrix or series is visible at a glance?
+//!
+//! How do I highlight values that are over a warning or critical threshold in green, yellow or red?
+//!
+//! How do I give each thread id or module name in a log its own color, the same on every run?
+//!
+//! ## Use
+//!
+//! * iter.map(rgb_hex)
+//! * iter.map(rgba_lower_hex)
+//! * iter.map(heat_color(min, max))
+//! * iter.map(color_by_threshold(warn, crit))
+//! * iter.map(color_by_hash)
+//!
+use crate::cast::ToF64;
+use std::fmt::Display;
+
+/// The ANSI escape sequence that resets all colors and styles
+pub(crate) const ANSI_RESET: &str = "\x1b[0m";
+
+/// A color that can be split into red, green and blue components
+///
+/// Implemented for (u8, u8, u8) tuples, [u8; 3] arrays, u32 packed as 0xRRGGBB
+/// (the most significant byte is ignored) and references to them.
+pub trait ToRgb {
+    /// The red, green and blue components
+    fn to_rgb(&self) -> [u8; 3];
+}
+
+/// A color that can be split into red, green, blue and alpha components
+///
+/// Implemented for (u8, u8, u8, u8) tuples, [u8; 4] arrays, u32 packed as 0xRRGGBBAA and references to them.
+pub trait ToRgba {
+    /// The red, green, blue and alpha components
+    fn to_rgba(&self) -> [u8; 4];
+}
+
+impl ToRgb for (u8, u8, u8) {
+    fn to_rgb(&self) -> [u8; 3] {
+        [self.0, self.1, self.2]
+    }
+}
+
+impl ToRgb for [u8; 3] {
+    fn to_rgb(&self) -> [u8; 3] {
+        *self
+    }
+}
+
+impl ToRgb for u32 {
+    fn to_rgb(&self) -> [u8; 3] {
+        let [_, r, g, b] = self.to_be_bytes();
+        [r, g, b]
+    }
+}
+
+impl<T: ToRgb + ?Sized> ToRgb for &T {
+    fn to_rgb(&self) -> [u8; 3] {
+        (**self).to_rgb()
+    }
+}
+
+impl ToRgba for (u8, u8, u8, u8) {
+    fn to_rgba(&self) -> [u8; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+}
+
+impl ToRgba for [u8; 4] {
+    fn to_rgba(&self) -> [u8; 4] {
+        *self
+    }
+}
+
+impl ToRgba for u32 {
+    fn to_rgba(&self) -> [u8; 4] {
+        self.to_be_bytes()
+    }
+}
+
+impl<T: ToRgba + ?Sized> ToRgba for &T {
+    fn to_rgba(&self) -> [u8; 4] {
+        (**self).to_rgba()
+    }
+}
+
+/// Convert a color into an upper case hexadecimal `#RRGGBB` String
+///
+/// Use .map(rgb_hex)
+///
+/// short for **format!("#{:02X}{:02X}{:02X}",r,g,b)**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let colors = vec![(0x1A, 0x2B, 0x3C), (255, 255, 255)];
+/// let vec_to_strings: Vec<_> = colors.iter().map(rgb_hex).collect();
+/// let expected = vec!["#1A2B3C", "#FFFFFF"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(rgb_hex(0x1A2B3C_u32), "#1A2B3C");
+/// ```
+pub fn rgb_hex<C: ToRgb>(color: C) -> String {
+    let [r, g, b] = color.to_rgb();
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
+/// Convert a color into a lower case hexadecimal `#rrggbb` String
+///
+/// Use .map(rgb_lower_hex)
+///
+/// short for **format!("#{:02x}{:02x}{:02x}",r,g,b)**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let colors: Vec<u32> = vec![0x1A2B3C, 0xC0FFEE];
+/// let vec_to_strings: Vec<_> = colors.into_iter().map(rgb_lower_hex).collect();
+/// let expected = vec!["#1a2b3c", "#c0ffee"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn rgb_lower_hex<C: ToRgb>(color: C) -> String {
+    let [r, g, b] = color.to_rgb();
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Convert a color with alpha into an upper case hexadecimal `#RRGGBBAA` String
+///
+/// Use .map(rgba_hex)
+///
+/// short for **format!("#{:02X}{:02X}{:02X}{:02X}",r,g,b,a)**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let colors = vec![[0x1A, 0x2B, 0x3C, 0xFF], [0, 0, 0, 0x80]];
+/// let vec_to_strings: Vec<_> = colors.iter().map(rgba_hex).collect();
+/// let expected = vec!["#1A2B3CFF", "#00000080"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(rgba_hex(0x1A2B3CFF_u32), "#1A2B3CFF");
+/// ```
+pub fn rgba_hex<C: ToRgba>(color: C) -> String {
+    let [r, g, b, a] = color.to_rgba();
+    format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+}
+
+/// Convert a color with alpha into a lower case hexadecimal `#rrggbbaa` String
+///
+/// Use .map(rgba_lower_hex)
+///
+/// short for **format!("#{:02x}{:02x}{:02x}{:02x}",r,g,b,a)**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = rgba_lower_hex((0x1A, 0x2B, 0x3C, 0xFF));
+/// assert_eq!(result, "#1a2b3cff");
+/// ```
+pub fn rgba_lower_hex<C: ToRgba>(color: C) -> String {
+    let [r, g, b, a] = color.to_rgba();
+    format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+}
+
+/// Returns a mapper that formats each number with a truecolor ANSI background,
+/// scaled from blue for min to red for max
+///
+/// Use .map(heat_color(min, max))
+///
+/// Values outside min..=max are clamped to blue or red.
+/// The terminal must support 24 bit color.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![0.0, 5.0, 10.0];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(heat_color(0.0, 10.0)).collect();
+/// let expected = vec![
+///     "\x1b[48;2;0;0;255m0\x1b[0m",
+///     "\x1b[48;2;128;0;127m5\x1b[0m",
+///     "\x1b[48;2;255;0;0m10\x1b[0m",
+/// ];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// let readings: Vec<u8> = vec![12, 200, 90];
+/// for colored in readings.iter().map(heat_color(0, 255)) {
+///     print!("{} ", colored);
+/// }
+/// ```
+pub fn heat_color<N: ToF64, V: ToF64 + Display>(min: N, max: N) -> impl Fn(V) -> String {
+    let (min, max) = (min.to_f64(), max.to_f64());
+    move |n| {
+        let range = max - min;
+        let t = if range > 0.0 {
+            ((n.to_f64() - min) / range).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let red = (255.0 * t).round() as u8;
+        let blue = 255 - red;
+        format!("\x1b[48;2;{};0;{}m{}{}", red, blue, n, ANSI_RESET)
+    }
+}
+
+/// Returns a mapper that formats each number in the ANSI foreground color green, yellow or red,
+/// depending on whether it has reached the warn or crit threshold
+///
+/// Use .map(color_by_threshold(warn, crit))
+///
+/// A value is red if it is at least crit, yellow if it is at least warn and green otherwise.
+/// If warn is greater than crit lower values are worse, e.g.: free disk space,
+/// so a value is red if it is at most crit and yellow if it is at most warn.
+/// NaN is red, as it is never in range.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let cpu_percent = vec![12, 75, 98];
+/// let vec_to_strings: Vec<_> = cpu_percent.into_iter().map(color_by_threshold(70, 90)).collect();
+/// let expected = vec!["\x1b[32m12\x1b[0m", "\x1b[33m75\x1b[0m", "\x1b[31m98\x1b[0m"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// let free_gb = vec![120.5, 8.0, 0.5];
+/// let vec_to_strings: Vec<_> = free_gb.iter().map(color_by_threshold(10.0, 1.0)).collect();
+/// let expected = vec!["\x1b[32m120.5\x1b[0m", "\x1b[33m8\x1b[0m", "\x1b[31m0.5\x1b[0m"];
+/// assert_eq!(vec_to_strings, expected);
+///# assert_eq!(color_by_threshold(1.0, 2.0)(f64::NAN), "\x1b[31mNaN\x1b[0m");
+///# assert_eq!(color_by_threshold(70, 90)(90), "\x1b[31m90\x1b[0m");
+///# assert_eq!(color_by_threshold(10, 1)(10), "\x1b[33m10\x1b[0m");
+/// ```
+pub fn color_by_threshold<N: ToF64, V: ToF64 + Display>(warn: N, crit: N) -> impl Fn(V) -> String {
+    let (warn, crit) = (warn.to_f64(), crit.to_f64());
+    move |n| {
+        let value = n.to_f64();
+        let reached = |threshold: f64| {
+            if warn <= crit {
+                value >= threshold
+            } else {
+                value <= threshold
+            }
+        };
+        let color = if value.is_nan() || reached(crit) {
+            31
+        } else if reached(warn) {
+            33
+        } else {
+            32
+        };
+        format!("\x1b[{}m{}{}", color, n, ANSI_RESET)
+    }
+}
+
+/// The 64 bit FNV-1a hash of bytes, which unlike the std Hasher is the same on every platform and release
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The colors of the ANSI 256 color 6x6x6 cube that are readable on both dark and light backgrounds,
+/// neither gray nor too dark nor too pale
+fn hash_palette() -> impl Iterator<Item = u8> {
+    (16..232_u8).filter(|&color| {
+        let cube = color - 16;
+        let (r, g, b) = (cube / 36, cube / 6 % 6, cube % 6);
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        max >= 2 && min <= 2 && max != min
+    })
+}
+
+/// Convert a value that implements [`std::fmt::Display`] into a String in an ANSI 256 color foreground
+/// chosen by the hash of its text, so equal values always have the same color
+///
+/// Use .map(color_by_hash)
+///
+/// Useful to group repeated identifiers in log output, e.g.: thread ids or module names.
+/// The hash is FNV-1a, so the color of a value is the same on every run, platform and release of this crate.
+/// The palette leaves out grays and colors that are too dark or too pale to read.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let threads = vec!["worker-1", "worker-2", "worker-1"];
+/// let colored: Vec<_> = threads.iter().map(color_by_hash).collect();
+/// assert_eq!(colored[0], colored[2]);
+/// assert_ne!(colored[0], colored[1]);
+/// assert_eq!(colored[0], "\x1b[38;5;69mworker-1\x1b[0m");
+///# assert_eq!(color_by_hash(42), "\x1b[38;5;116m42\x1b[0m");
+/// ```
+pub fn color_by_hash<D: Display>(d: D) -> String {
+    let text = d.to_string();
+    let palette_len = hash_palette().count() as u64;
+    let index = (fnv1a(text.as_bytes()) % palette_len) as usize;
+    let color = hash_palette()
+        .nth(index)
+        .expect("index is less than the palette length");
+    format!("\x1b[38;5;{}m{}{}", color, text, ANSI_RESET)
+}
@@ -0,0 +1,109 @@
+//! Provides functions for showing where two formatted Strings differ
+//!
+//! ## The answer to the question:
+//!
+//! How do I see where a big multi line String differs from the expected output when a test fails?
+//!
+//! ## Use
+//!
+//! * assert!(expected == actual, "{}", diff_pretty(expected, actual))
+//!
+use crate::align::display_width;
+
+/// The number of lines shown before and after the first difference
+const CONTEXT_LINES: usize = 2;
+
+/// Shown in place of a line that does not exist because the other text is longer
+const END_OF_TEXT: &str = "(end of text)";
+
+/// Convert an expected and an actual String or &str into a report of where they first differ,
+/// for use in assert messages when comparing big formatted outputs
+///
+/// The report shows the line and column of the first difference,
+/// the 2 lines of context before and after it, the differing expected `-` and actual `+` lines,
+/// and a `^` under the first differing char. Returns an empty String if they are equal.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let expected = "fn main() {\n    println!(\"Hello\");\n}\n";
+/// let actual = "fn main() {\n    println!(\"Hallo\");\n}\n";
+/// let report = concat!(
+///     "first difference at line 2, column 16\n",
+///     "  1 | fn main() {\n",
+///     "- 2 |     println!(\"Hello\");\n",
+///     "+ 2 |     println!(\"Hallo\");\n",
+///     "    |                ^\n",
+///     "  3 | }\n",
+///     "  4 |",
+/// );
+/// assert_eq!(diff_pretty(expected, actual), report);
+///
+/// let report = diff_pretty("a\nb", "a\nb\n");
+/// assert_eq!(report, "first difference at line 3, column 1\n  1 | a\n  2 | b\n- 3 | (end of text)\n+ 3 |\n    | ^");
+///
+/// assert_eq!(diff_pretty("same", "same"), "");
+///# assert!("same" == "same", "{}", diff_pretty("same", "same"));
+/// ```
+pub fn diff_pretty<E: AsRef<str>, A: AsRef<str>>(expected: E, actual: A) -> String {
+    let (expected, actual) = (expected.as_ref(), actual.as_ref());
+    if expected == actual {
+        return String::new();
+    }
+    let expected_lines: Vec<&str> = expected.split('\n').collect();
+    let actual_lines: Vec<&str> = actual.split('\n').collect();
+    let line = expected_lines
+        .iter()
+        .zip(&actual_lines)
+        .take_while(|(e, a)| e == a)
+        .count();
+    let (expected_line, actual_line) = (expected_lines.get(line), actual_lines.get(line));
+    let same_prefix: String = match (expected_line, actual_line) {
+        (Some(e), Some(a)) => e
+            .chars()
+            .zip(a.chars())
+            .take_while(|(e, a)| e == a)
+            .map(|(e, _)| e)
+            .collect(),
+        _ => String::new(),
+    };
+    let last = (line + CONTEXT_LINES).min(expected_lines.len().max(actual_lines.len()) - 1);
+    let number_width = (last + 1).to_string().len();
+    let numbered = |marker: char, number: usize, text: Option<&&str>| {
+        let text = text.copied().unwrap_or(END_OF_TEXT);
+        let mut formatted = format!("{} {:>w$} |", marker, number + 1, w = number_width);
+        if !text.is_empty() {
+            formatted.push(' ');
+            formatted.push_str(text);
+        }
+        formatted
+    };
+
+    let mut report = vec![format!(
+        "first difference at line {}, column {}",
+        line + 1,
+        same_prefix.chars().count() + 1
+    )];
+    for i in line.saturating_sub(CONTEXT_LINES)..line {
+        report.push(numbered(' ', i, expected_lines.get(i)));
+    }
+    report.push(numbered('-', line, expected_line));
+    report.push(numbered('+', line, actual_line));
+    report.push(format!(
+        "  {:w$} | {:p$}^",
+        "",
+        "",
+        w = number_width,
+        p = display_width(&same_prefix)
+    ));
+    for i in line + 1..=last {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => report.push(numbered(' ', i, Some(e))),
+            (e, a) => {
+                report.push(numbered('-', i, e));
+                report.push(numbered('+', i, a));
+            }
+        }
+    }
+    report.join("\n")
+}
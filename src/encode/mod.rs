@@ -0,0 +1,785 @@
+//! Provides functions for encoding bytes as text
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert bytes into an xxd style hex dump?
+//!
+//! How do I convert megabytes of bytes into a hexadecimal String quickly?
+//!
+//! How do I convert `Bytes` or a chain of buffers from an async networking stack into hexadecimal?
+//!
+//! How do I convert bytes into base64, e.g.: for a data URL or an HTTP Basic auth header?
+//!
+//! How do I convert bytes into Ascii85 for a PDF stream, or Z85 for a ZeroMQ key, and back?
+//!
+//! How do I convert text into quoted-printable for a MIME email body, and back?
+//!
+//! How do I convert a number into packed BCD for a smartcard, RTC or industrial protocol, and back?
+//!
+//! How do I collect an iterator of bytes directly into hexadecimal, e.g.: `DE:AD:BE:EF`?
+//!
+//! How do I show the printable ASCII chars of binary data, like the right hand column of a hex dump?
+//!
+//! ## Use
+//!
+//! * hexdump(bytes)
+//! * hex_string(bytes)
+//! * base64(bytes)
+//! * base85_encode(bytes, Base85::Z85) and base85_decode(s, Base85::Z85)
+//! * iter.map(quoted_printable_encode) and iter.filter_map(quoted_printable_decode)
+//! * iter.map(to_bcd) and iter.filter_map(bcd_to_string)
+//! * bytes.iter().map(ascii_or_dot)
+//! * bytes.iter().collect::<HexString>().with_separator(":")
+//! * hex_string_buf(buf), hex_chunk_join_buf(buf, 2, " ") and hexdump_buf(buf) with the `bytes` feature
+//!
+//! `Bytes` and `BytesMut` from the [`bytes`](https://docs.rs/bytes) crate can be passed to the functions directly,
+//! enable the `bytes` feature to format any `Buf`, e.g.: a chain of buffers, without copying it into a Vec first.
+//!
+//! Enable the `simd` feature to encode hexadecimal with SSSE3 or AVX2 on x86 and NEON on aarch64,
+//! the instructions are detected at runtime and the scalar code is used when they are not available.
+//!
+#[cfg(feature = "simd")]
+mod simd;
+
+use crate::fmt::{push_hex_pairs, IntegerMagnitude, LOWER_HEX_PAIRS, UPPER_HEX_PAIRS};
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter, Write as _};
+
+/// The number of bytes shown on each line of a hex dump
+pub const HEXDUMP_BYTES_PER_LINE: usize = 16;
+
+/// Write the lower case hexadecimal digits of bytes into hex, which is twice as long
+fn hex_encode_scalar(bytes: &[u8], hex: &mut [u8]) {
+    for (&byte, pair) in bytes.iter().zip(hex.chunks_exact_mut(2)) {
+        pair.copy_from_slice(&LOWER_HEX_PAIRS[usize::from(byte)]);
+    }
+}
+
+/// Convert bytes into a lower case hexadecimal String, two digits per byte
+///
+/// short for **bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>()**,
+/// but each byte is looked up in a table instead of using the formatting machinery,
+/// with the `simd` feature 16 or 32 bytes are encoded at a time, see `benches/hex.rs`.
+///
+/// Use [`crate::secret::hex_string_ct`] for secret keys.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = hex_string([0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x9F]);
+/// assert_eq!(result, "deadbeef009f");
+///
+/// let packets = vec![vec![1_u8, 2], vec![255]];
+/// let vec_to_strings: Vec<_> = packets.iter().map(hex_string).collect();
+/// assert_eq!(vec_to_strings, vec!["0102", "ff"]);
+///# for len in [0, 1, 15, 16, 17, 31, 32, 33, 64, 100, 1000] {
+///#     let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 11) as u8).collect();
+///#     let expected: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+///#     assert_eq!(hex_string(&bytes), expected);
+///# }
+/// ```
+pub fn hex_string<B: AsRef<[u8]>>(bytes: B) -> String {
+    let bytes = bytes.as_ref();
+    let mut hex = vec![0; bytes.len() * 2];
+    hex_encode(bytes, &mut hex);
+    // SAFETY: every byte was written with an ASCII hexadecimal digit
+    unsafe { String::from_utf8_unchecked(hex) }
+}
+
+/// Write the lower case hexadecimal digits of bytes into hex, which is twice as long,
+/// with SIMD instructions if the `simd` feature is enabled
+fn hex_encode(bytes: &[u8], hex: &mut [u8]) {
+    #[cfg(feature = "simd")]
+    let encoded = simd::hex_encode(bytes, hex);
+    #[cfg(not(feature = "simd"))]
+    let encoded = 0;
+    hex_encode_scalar(&bytes[encoded..], &mut hex[encoded * 2..]);
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+/// Convert the remaining bytes of a [`bytes::Buf`] into a lower case hexadecimal String, like [`hex_string`]
+///
+/// The buffer is consumed chunk by chunk, so a non contiguous buffer, e.g.: a chain of `Bytes`,
+/// is not copied into a Vec first. Pass a clone of a `Bytes`, which is cheap, or `&mut buf` to keep it.
+///
+/// # Example
+/// ```
+/// use bytes::{Buf, Bytes, BytesMut};
+/// use i2u::prelude::*;
+/// let header = Bytes::from_static(&[0xCA, 0xFE]);
+/// let mut body = BytesMut::new();
+/// body.extend_from_slice(&[0xBA, 0xBE]);
+/// assert_eq!(hex_string_buf(header.clone().chain(body.freeze())), "cafebabe");
+///
+/// // contiguous buffers can also be passed to hex_string without copying
+/// assert_eq!(hex_string(&header), "cafe");
+/// ```
+pub fn hex_string_buf<B: bytes::Buf>(mut buf: B) -> String {
+    let mut hex = vec![0; buf.remaining() * 2];
+    let mut offset = 0;
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+        let len = chunk.len();
+        hex_encode(chunk, &mut hex[offset..offset + len * 2]);
+        offset += len * 2;
+        buf.advance(len);
+    }
+    // SAFETY: every byte was written with an ASCII hexadecimal digit
+    unsafe { String::from_utf8_unchecked(hex) }
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+/// Convert the remaining bytes of a [`bytes::Buf`] into lower case hexadecimal, in groups of chunk_size bytes
+/// joined with separator
+///
+/// short for **chunk_join(hex_string_buf(buf), chunk_size * 2, separator)**,
+/// but the groups can span the chunks of the buffer and no intermediate String is built.
+///
+/// # Example
+/// ```
+/// use bytes::{Buf, Bytes};
+/// use i2u::prelude::*;
+/// let (header, body) = (Bytes::from_static(&[0xDE, 0xAD, 0xBE]), Bytes::from_static(&[0xEF, 0x01]));
+/// let result = hex_chunk_join_buf(header.clone().chain(body.clone()), 1, " ");
+/// assert_eq!(result, "de ad be ef 01");
+/// assert_eq!(hex_chunk_join_buf(header.chain(body), 2, ":"), "dead:beef:01");
+/// ```
+pub fn hex_chunk_join_buf<B: bytes::Buf, S: AsRef<str>>(
+    mut buf: B,
+    chunk_size: usize,
+    separator: S,
+) -> String {
+    assert!(chunk_size > 0);
+    let separator = separator.as_ref();
+    let remaining = buf.remaining();
+    let mut hex = String::with_capacity(remaining * 2 + remaining / chunk_size * separator.len());
+    let mut count = 0;
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+        let len = chunk.len();
+        for &byte in chunk {
+            if count > 0 && count % chunk_size == 0 {
+                hex.push_str(separator);
+            }
+            let [high, low] = LOWER_HEX_PAIRS[usize::from(byte)];
+            hex.push(char::from(high));
+            hex.push(char::from(low));
+            count += 1;
+        }
+        buf.advance(len);
+    }
+    hex
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+/// Convert the remaining bytes of a [`bytes::Buf`] into an xxd style hex dump, like [`hexdump`]
+///
+/// The lines are assembled across chunk boundaries, so a non contiguous buffer is not copied into a Vec first.
+///
+/// # Example
+/// ```
+/// use bytes::{Buf, Bytes};
+/// use i2u::prelude::*;
+/// let request = Bytes::from_static(b"GET / HTTP/1.1\r\n").chain(Bytes::from_static(b"\r\n"));
+/// let expected = concat!(
+///     "00000000: 4745 5420 2f20 4854 5450 2f31 2e31 0d0a  GET / HTTP/1.1..\n",
+///     "00000010: 0d0a                                     ..\n",
+/// );
+/// assert_eq!(hexdump_buf(request), expected);
+/// ```
+pub fn hexdump_buf<B: bytes::Buf>(mut buf: B) -> String {
+    let mut dump = String::new();
+    let mut line = Vec::with_capacity(HEXDUMP_BYTES_PER_LINE);
+    let mut offset = 0;
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+        let len = chunk.len();
+        for &byte in chunk {
+            line.push(byte);
+            if line.len() == HEXDUMP_BYTES_PER_LINE {
+                dump.push_str(&hexdump_line(offset, &line));
+                dump.push('\n');
+                offset += HEXDUMP_BYTES_PER_LINE;
+                line.clear();
+            }
+        }
+        buf.advance(len);
+    }
+    if !line.is_empty() {
+        dump.push_str(&hexdump_line(offset, &line));
+        dump.push('\n');
+    }
+    dump
+}
+
+/// The base64 alphabet of RFC 4648
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Convert bytes into a base64 String with the standard alphabet and `=` padding, as defined by RFC 4648
+///
+/// Use .map(base64)
+///
+/// Use [`crate::io::WrapWriter`] to break the output into lines, e.g.: 64 chars wide for PEM.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["", "f", "fo", "foo", "foob", "fooba", "foobar"];
+/// let vec_to_strings: Vec<_> = vec.iter().map(base64).collect();
+/// let expected = vec!["", "Zg==", "Zm8=", "Zm9v", "Zm9vYg==", "Zm9vYmE=", "Zm9vYmFy"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(base64([0xFB, 0xFF]), "+/8=");
+/// ```
+pub fn base64<B: AsRef<[u8]>>(bytes: B) -> String {
+    let bytes = bytes.as_ref();
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = chunk.iter().enumerate().fold(0_u32, |triple, (i, &byte)| {
+            triple | u32::from(byte) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (triple >> (18 - 6 * i)) & 0x3F;
+                encoded.push(char::from(BASE64_ALPHABET[index as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// The alphabet and rules used by [`base85_encode`] and [`base85_decode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Base85 {
+    /// Ascii85 as written by btoa and used in PDF and PostScript, the chars `!` to `u`,
+    /// with `z` for four zero bytes
+    #[default]
+    Ascii85,
+    /// Z85 as specified by ZeroMQ RFC 32, an alphabet without quotes and backslashes
+    /// so it can be embedded in source code, JSON and XML
+    Z85,
+}
+
+/// The Z85 alphabet of ZeroMQ RFC 32
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+impl Base85 {
+    /// The char for a base 85 digit
+    fn encode_digit(self, digit: u32) -> char {
+        match self {
+            Base85::Ascii85 => char::from(b'!' + digit as u8),
+            Base85::Z85 => char::from(Z85_ALPHABET[digit as usize]),
+        }
+    }
+
+    /// The base 85 digit of a char, or None if it is not in the alphabet
+    fn decode_digit(self, c: u8) -> Option<u32> {
+        match self {
+            Base85::Ascii85 => (b'!'..=b'u').contains(&c).then(|| u32::from(c - b'!')),
+            Base85::Z85 => Z85_ALPHABET
+                .iter()
+                .position(|&z| z == c)
+                .map(|digit| digit as u32),
+        }
+    }
+}
+
+/// Convert bytes into base85, each group of 4 bytes as 5 chars of variant's alphabet
+///
+/// A final group of n < 4 bytes is padded with zeros and written as its first n + 1 chars, as Ascii85 does,
+/// Z85 strictly requires a multiple of 4 bytes, so pad the bytes first if the decoder is strict.
+/// The Ascii85 `<~` and `~>` delimiters are not added.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(base85_encode("Man is", Base85::Ascii85), "9jqo^Bla");
+/// assert_eq!(base85_encode([0, 0, 0, 0, 1], Base85::Ascii85), "z!<");
+///
+/// let key = [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B];
+/// assert_eq!(base85_encode(key, Base85::Z85), "HelloWorld");
+/// ```
+pub fn base85_encode<B: AsRef<[u8]>>(bytes: B, variant: Base85) -> String {
+    let bytes = bytes.as_ref();
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(4) * 5);
+    for chunk in bytes.chunks(4) {
+        let group = chunk.iter().enumerate().fold(0_u32, |group, (i, &byte)| {
+            group | u32::from(byte) << (24 - 8 * i)
+        });
+        if group == 0 && chunk.len() == 4 && variant == Base85::Ascii85 {
+            encoded.push('z');
+            continue;
+        }
+        let mut digits = [0; 5];
+        let mut rest = group;
+        for digit in digits.iter_mut().rev() {
+            *digit = rest % 85;
+            rest /= 85;
+        }
+        for &digit in &digits[..=chunk.len()] {
+            encoded.push(variant.encode_digit(digit));
+        }
+    }
+    encoded
+}
+
+/// Convert base85 text in variant's alphabet back into bytes,
+/// or None if it has a char outside the alphabet, a group too large for 4 bytes or a final group of 1 char
+///
+/// Whitespace is ignored, and for Ascii85 the `<~` and `~>` delimiters are optional,
+/// so an Ascii85 stream can be decoded as it appears in a PDF.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let decoded = base85_decode("<~9jqo^Bla~>", Base85::Ascii85).unwrap();
+/// assert_eq!(decoded, b"Man is");
+/// assert_eq!(base85_decode("HelloWorld", Base85::Z85).unwrap(), [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]);
+///
+/// assert_eq!(base85_decode("9jqo{", Base85::Ascii85), None);
+///# assert_eq!(base85_decode("z!<", Base85::Ascii85).unwrap(), [0, 0, 0, 0, 1]);
+///# assert_eq!(base85_decode("9jqo^\n Bla", Base85::Ascii85).unwrap(), b"Man is");
+///# assert_eq!(base85_decode("s8W-\"", Base85::Ascii85), None);
+///# assert_eq!(base85_decode("s8W-!", Base85::Ascii85).unwrap(), [0xFF; 4]);
+///# assert_eq!(base85_decode("9", Base85::Ascii85), None);
+///# assert_eq!(base85_decode("9z", Base85::Ascii85), None);
+///# for len in 0..12 {
+///#     let bytes: Vec<u8> = (0..len).map(|i| (i * 37) as u8).collect();
+///#     for variant in [Base85::Ascii85, Base85::Z85] {
+///#         assert_eq!(base85_decode(base85_encode(&bytes, variant), variant).unwrap(), bytes);
+///#     }
+///# }
+/// ```
+pub fn base85_decode<S: AsRef<str>>(s: S, variant: Base85) -> Option<Vec<u8>> {
+    let mut text = s.as_ref().trim();
+    if variant == Base85::Ascii85 {
+        text = text.strip_prefix("<~").unwrap_or(text);
+        text = text.strip_suffix("~>").unwrap_or(text);
+    }
+    let mut decoded = Vec::with_capacity(text.len() / 5 * 4 + 4);
+    let mut digits = Vec::with_capacity(5);
+    for c in text.bytes().filter(|c| !c.is_ascii_whitespace()) {
+        if c == b'z' && variant == Base85::Ascii85 {
+            if !digits.is_empty() {
+                return None;
+            }
+            decoded.extend([0; 4]);
+            continue;
+        }
+        digits.push(variant.decode_digit(c)?);
+        if digits.len() == 5 {
+            decoded.extend(base85_group(&digits)?);
+            digits.clear();
+        }
+    }
+    match digits.len() {
+        0 => {}
+        1 => return None,
+        len => {
+            // pad with the largest digit, so the truncated group rounds up to the original bytes
+            digits.resize(5, 84);
+            decoded.extend(&base85_group(&digits)?[..len - 1]);
+        }
+    }
+    Some(decoded)
+}
+
+/// The 4 bytes of 5 base 85 digits, or None if they are larger than u32::MAX
+fn base85_group(digits: &[u32]) -> Option<[u8; 4]> {
+    let group = digits.iter().try_fold(0_u32, |group, &digit| {
+        group.checked_mul(85)?.checked_add(digit)
+    })?;
+    Some(group.to_be_bytes())
+}
+
+/// The maximum length of a quoted-printable line, excluding the CRLF, as defined by RFC 2045
+const QUOTED_PRINTABLE_LINE_LEN: usize = 76;
+
+/// Convert bytes into quoted-printable, as defined by RFC 2045 for MIME email bodies
+///
+/// Use .map(quoted_printable_encode)
+///
+/// Printable ASCII is kept as is, `=`, control chars and non ASCII bytes are written as `=XX`,
+/// as are a space or tab at the end of a line. `\n` and `\r\n` are line breaks and are written as `\r\n`,
+/// longer lines are split with a soft line break, `=` then `\r\n`, so no line is longer than 76 chars.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["Größe = 3 ", "line 1\nline 2"];
+/// let vec_to_strings: Vec<_> = vec.iter().map(quoted_printable_encode).collect();
+/// assert_eq!(vec_to_strings, vec!["Gr=C3=B6=C3=9Fe =3D 3=20", "line 1\r\nline 2"]);
+///
+/// let encoded = quoted_printable_encode("a".repeat(100));
+/// assert_eq!(encoded, format!("{}=\r\n{}", "a".repeat(75), "a".repeat(25)));
+///# assert_eq!(quoted_printable_encode("a".repeat(76)), "a".repeat(76));
+///# assert_eq!(quoted_printable_encode(format!("{}é", "a".repeat(74))), format!("{}=\r\n=C3=A9", "a".repeat(74)));
+///# assert_eq!(quoted_printable_encode("tab\t\r\nend\t"), "tab=09\r\nend=09");
+/// ```
+pub fn quoted_printable_encode<B: AsRef<[u8]>>(bytes: B) -> String {
+    let bytes = bytes.as_ref();
+    let mut encoded = String::with_capacity(bytes.len() + bytes.len() / 8);
+    let mut line_len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        let line_break = match (byte, bytes.get(i + 1)) {
+            (b'\n', _) => 1,
+            (b'\r', Some(b'\n')) => 2,
+            _ => 0,
+        };
+        if line_break > 0 {
+            encoded.push_str("\r\n");
+            line_len = 0;
+            i += line_break;
+            continue;
+        }
+        let at_line_end = match bytes.get(i + 1) {
+            None | Some(b'\n') => true,
+            Some(b'\r') => bytes.get(i + 2) == Some(&b'\n'),
+            _ => false,
+        };
+        let literal = matches!(byte, b'!'..=b'<' | b'>'..=b'~')
+            || (matches!(byte, b' ' | b'\t') && !at_line_end);
+        let len = if literal { 1 } else { 3 };
+        // leave room for the = of a soft line break, unless this is the last byte of the line
+        let limit = QUOTED_PRINTABLE_LINE_LEN - usize::from(!at_line_end);
+        if line_len + len > limit {
+            encoded.push_str("=\r\n");
+            line_len = 0;
+        }
+        if literal {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push('=');
+            encoded.push_str(
+                std::str::from_utf8(&UPPER_HEX_PAIRS[usize::from(byte)]).expect("hex is ASCII"),
+            );
+        }
+        line_len += len;
+        i += 1;
+    }
+    encoded
+}
+
+/// Convert quoted-printable text back into bytes, or None if it has an `=` that is not followed by
+/// two hexadecimal digits or a line break
+///
+/// Use .filter_map(quoted_printable_decode)
+///
+/// Soft line breaks are removed, hard line breaks are kept as they are, `\n` or `\r\n`,
+/// and trailing spaces and tabs on a line are ignored, as they may have been added in transport.
+/// Lower case hexadecimal digits are accepted.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let decoded = quoted_printable_decode("Gr=C3=B6=C3=9Fe =3D 3=20").unwrap();
+/// assert_eq!(String::from_utf8(decoded).unwrap(), "Größe = 3 ");
+///
+/// let decoded = quoted_printable_decode("soft=\r\nbreak\r\nhard   ").unwrap();
+/// assert_eq!(decoded, b"softbreak\r\nhard");
+///
+/// assert_eq!(quoted_printable_decode("=ZZ"), None);
+///# assert_eq!(quoted_printable_decode("=c3=a9=\n").unwrap(), "é".as_bytes());
+///# assert_eq!(quoted_printable_decode("end=").unwrap(), b"end");
+///# assert_eq!(quoted_printable_decode("=4"), None);
+///# for text in ["", "x", "line\r\n", "a =\tb \r\n\r\n\t", &"é ".repeat(60)] {
+///#     let encoded = quoted_printable_encode(text);
+///#     assert!(encoded.split("\r\n").all(|line| line.len() <= 76));
+///#     let decoded = quoted_printable_decode(encoded).unwrap();
+///#     assert_eq!(decoded, text.replace("\r\n", "\n").replace('\n', "\r\n").as_bytes());
+///# }
+/// ```
+pub fn quoted_printable_decode<S: AsRef<str>>(s: S) -> Option<Vec<u8>> {
+    let s = s.as_ref();
+    let mut decoded = Vec::with_capacity(s.len());
+    let mut lines = s.split('\n').peekable();
+    while let Some(line) = lines.next() {
+        let (line, ending) = match line.strip_suffix('\r') {
+            Some(line) => (line, "\r\n"),
+            None => (line, "\n"),
+        };
+        let line = line.trim_end_matches([' ', '\t']);
+        // an escape always ends with a hex digit, so a final = is a soft line break
+        let (line, soft_break) = match line.strip_suffix('=') {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        let mut bytes = line.bytes();
+        while let Some(byte) = bytes.next() {
+            if byte == b'=' {
+                let high = char::from(bytes.next()?).to_digit(16)?;
+                let low = char::from(bytes.next()?).to_digit(16)?;
+                decoded.push((high * 16 + low) as u8);
+            } else {
+                decoded.push(byte);
+            }
+        }
+        if !soft_break && lines.peek().is_some() {
+            decoded.extend(ending.bytes());
+        }
+    }
+    Some(decoded)
+}
+
+/// Convert a non negative integer into packed BCD, two decimal digits per byte, most significant first
+///
+/// Use .map(to_bcd)
+///
+/// An odd number of digits is padded with a leading zero digit, so the hexadecimal of the bytes
+/// reads as the decimal number, e.g.: 1234 is `[0x12, 0x34]` and 123 is `[0x01, 0x23]`.
+///
+/// # Panics
+///
+/// If the integer is negative, as packed BCD has no standard sign
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1234, 123, 0, 59];
+/// let vec_to_bcd: Vec<_> = vec.iter().map(to_bcd).collect();
+/// assert_eq!(vec_to_bcd, vec![vec![0x12, 0x34], vec![0x01, 0x23], vec![0x00], vec![0x59]]);
+///
+/// assert_eq!(hex_string(to_bcd(20231231_u32)), "20231231");
+///# assert_eq!(to_bcd(u128::MAX).len(), 20);
+/// ```
+pub fn to_bcd<I: IntegerMagnitude>(i: I) -> Vec<u8> {
+    assert!(
+        !i.is_negative(),
+        "BCD can only encode non negative integers"
+    );
+    let mut n = i.magnitude();
+    let mut bcd = Vec::with_capacity(20);
+    loop {
+        bcd.push((n / 10 % 10) as u8 * 16 + (n % 10) as u8);
+        n /= 100;
+        if n == 0 {
+            break;
+        }
+    }
+    bcd.reverse();
+    bcd
+}
+
+/// Convert packed BCD back into a String of its decimal digits, two per byte, most significant first,
+/// or None if a nibble is greater than 9
+///
+/// Use .filter_map(bcd_to_string)
+///
+/// Leading zeros are kept, as they are significant in fields such as dates and card numbers,
+/// parse the String to get a number.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let time = [0x09, 0x30, 0x05];
+/// assert_eq!(bcd_to_string(time).unwrap(), "093005");
+/// assert_eq!(bcd_to_string(to_bcd(123)).unwrap(), "0123");
+///
+/// assert_eq!(bcd_to_string([0x1A]), None);
+/// ```
+pub fn bcd_to_string<B: AsRef<[u8]>>(bytes: B) -> Option<String> {
+    let bytes = bytes.as_ref();
+    let mut digits = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        for nibble in [byte >> 4, byte & 0x0F] {
+            digits.push(char::from_digit(u32::from(nibble), 10)?);
+        }
+    }
+    Some(digits)
+}
+
+/// Convert a byte into its printable ASCII char, or `.` if it is a control char or not ASCII
+///
+/// Use .map(ascii_or_dot)
+///
+/// The same rendering as the ASCII column of [`hexdump`], space is printable.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let bytes = b"GIF89a\x01\x00\n\xff ok";
+/// let ascii: String = bytes.iter().map(ascii_or_dot).collect();
+/// assert_eq!(ascii, "GIF89a.... ok");
+/// ```
+pub fn ascii_or_dot<B: Borrow<u8>>(byte: B) -> char {
+    ascii_or(byte, '.')
+}
+
+/// Convert a byte into its printable ASCII char, or placeholder if it is a control char or not ASCII
+///
+/// Use .map(|b| ascii_or(b, '�'))
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let bytes = vec![b'a', 0, b'b', 0x7f];
+/// let ascii: String = bytes.iter().map(|b| ascii_or(b, '·')).collect();
+/// assert_eq!(ascii, "a·b·");
+/// ```
+pub fn ascii_or<B: Borrow<u8>>(byte: B, placeholder: char) -> char {
+    let byte = *byte.borrow();
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        placeholder
+    }
+}
+
+/// Format up to 16 bytes as a single xxd style line, without a trailing new line
+pub(crate) fn hexdump_line(offset: usize, bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(HEXDUMP_BYTES_PER_LINE * 5 / 2);
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 && i % 2 == 0 {
+            hex.push(' ');
+        }
+        push_hex_pairs(&mut hex, &[*byte], &LOWER_HEX_PAIRS);
+    }
+    let ascii: String = bytes.iter().map(ascii_or_dot).collect();
+    format!("{:08x}: {:<39}  {}", offset, hex, ascii)
+}
+
+/// Convert bytes into an xxd style hex dump, 16 bytes per line,
+/// with the offset, the bytes as hexadecimal in pairs and the printable ASCII chars
+///
+/// Each line ends with a new line, like the output of xxd.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = hexdump("Hello, World!\nhex dumps are useful");
+/// let expected = concat!(
+///     "00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a 6865  Hello, World!.he\n",
+///     "00000010: 7820 6475 6d70 7320 6172 6520 7573 6566  x dumps are usef\n",
+///     "00000020: 756c                                     ul\n",
+/// );
+/// assert_eq!(result, expected);
+/// ```
+pub fn hexdump<B: AsRef<[u8]>>(bytes: B) -> String {
+    bytes
+        .as_ref()
+        .chunks(HEXDUMP_BYTES_PER_LINE)
+        .enumerate()
+        .map(|(line, chunk)| hexdump_line(line * HEXDUMP_BYTES_PER_LINE, chunk) + "\n")
+        .collect()
+}
+
+/// Bytes collected from an iterator, displayed as hexadecimal, optionally in groups separated by a separator
+/// and in upper case
+///
+/// Implements [`FromIterator`] and [`Extend`] for bytes and references to them, so the bytes of any iterator
+/// can be collected without an intermediate Vec of Strings. The default is lower case without separators,
+/// the same as [`hex_string`].
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let mac = [0x00_u8, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E];
+/// let hex: HexString = mac.iter().collect();
+/// assert_eq!(hex.to_string(), "001a2b3c4d5e");
+/// assert_eq!(hex.with_separator(":").uppercase().to_string(), "00:1A:2B:3C:4D:5E");
+///
+/// let mut key: HexString = (0..8_u8).collect::<HexString>().with_separator(" ").with_group(4);
+/// assert_eq!(key.to_string(), "00010203 04050607");
+/// key.extend([0xFF_u8]);
+/// assert_eq!(format!("0x[{}]", key), "0x[00010203 04050607 ff]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HexString {
+    bytes: Vec<u8>,
+    separator: String,
+    group: usize,
+    upper: bool,
+}
+
+impl Default for HexString {
+    fn default() -> Self {
+        HexString {
+            bytes: Vec::new(),
+            separator: String::new(),
+            group: 1,
+            upper: false,
+        }
+    }
+}
+
+impl HexString {
+    /// An empty HexString, in lower case without separators
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Separate the groups of bytes with separator, e.g.: `" "` or `":"`
+    pub fn with_separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Put bytes_per_group bytes between each separator, 1 by default
+    ///
+    /// # Panics
+    ///
+    /// If bytes_per_group is 0
+    pub fn with_group(mut self, bytes_per_group: usize) -> Self {
+        assert!(
+            bytes_per_group > 0,
+            "bytes_per_group must be greater than 0"
+        );
+        self.group = bytes_per_group;
+        self
+    }
+
+    /// Show the hexadecimal digits in upper case
+    pub fn uppercase(mut self) -> Self {
+        self.upper = true;
+        self
+    }
+
+    /// The bytes collected so far
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<B: Borrow<u8>> FromIterator<B> for HexString {
+    fn from_iter<I: IntoIterator<Item = B>>(iter: I) -> Self {
+        let mut hex = HexString::new();
+        hex.extend(iter);
+        hex
+    }
+}
+
+impl<B: Borrow<u8>> Extend<B> for HexString {
+    fn extend<I: IntoIterator<Item = B>>(&mut self, iter: I) {
+        self.bytes.extend(iter.into_iter().map(|b| *b.borrow()));
+    }
+}
+
+impl Display for HexString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let pairs = if self.upper {
+            &UPPER_HEX_PAIRS
+        } else {
+            &LOWER_HEX_PAIRS
+        };
+        for (index, group) in self.bytes.chunks(self.group).enumerate() {
+            if index > 0 {
+                f.write_str(&self.separator)?;
+            }
+            for &byte in group {
+                let [high, low] = pairs[usize::from(byte)];
+                f.write_char(char::from(high))?;
+                f.write_char(char::from(low))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+//! SIMD implementations of the hexadecimal encoder, selected for the CPU the code runs on
+//!
+//! Each implementation encodes whole blocks and returns the number of bytes encoded,
+//! the remaining bytes are encoded by the scalar code.
+//!
+//! The digits are looked up with a byte shuffle, e.g.: `pshufb`, which uses each nibble as an index
+//! into a 16 byte register holding `0123456789abcdef`, so 16 or 32 bytes are encoded per instruction.
+#[cfg(target_arch = "aarch64")]
+use std::arch::aarch64::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::fmt::LOWER_HEX_DIGITS;
+
+/// Encode the bytes with AVX2 or SSSE3 if the CPU supports them, into hex which is twice as long
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) fn hex_encode(bytes: &[u8], hex: &mut [u8]) -> usize {
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: the CPU supports AVX2
+        unsafe { hex_encode_avx2(bytes, hex) }
+    } else if is_x86_feature_detected!("ssse3") {
+        // SAFETY: the CPU supports SSSE3
+        unsafe { hex_encode_ssse3(bytes, hex) }
+    } else {
+        0
+    }
+}
+
+/// Encode the bytes with NEON, which every aarch64 CPU supports, into hex which is twice as long
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn hex_encode(bytes: &[u8], hex: &mut [u8]) -> usize {
+    // SAFETY: NEON is a mandatory part of aarch64
+    unsafe { hex_encode_neon(bytes, hex) }
+}
+
+/// No SIMD implementation for this architecture, all the bytes are encoded by the scalar code
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+pub(crate) fn hex_encode(_bytes: &[u8], _hex: &mut [u8]) -> usize {
+    0
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "ssse3")]
+unsafe fn hex_encode_ssse3(bytes: &[u8], hex: &mut [u8]) -> usize {
+    let digits = _mm_loadu_si128(LOWER_HEX_DIGITS.as_ptr() as *const __m128i);
+    let mask = _mm_set1_epi8(0x0F);
+    let blocks = bytes.chunks_exact(16).zip(hex.chunks_exact_mut(32));
+    let mut encoded = 0;
+    for (input, output) in blocks {
+        let input = _mm_loadu_si128(input.as_ptr() as *const __m128i);
+        let high = _mm_shuffle_epi8(digits, _mm_and_si128(_mm_srli_epi16(input, 4), mask));
+        let low = _mm_shuffle_epi8(digits, _mm_and_si128(input, mask));
+        let output = output.as_mut_ptr() as *mut __m128i;
+        _mm_storeu_si128(output, _mm_unpacklo_epi8(high, low));
+        _mm_storeu_si128(output.add(1), _mm_unpackhi_epi8(high, low));
+        encoded += 16;
+    }
+    encoded
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn hex_encode_avx2(bytes: &[u8], hex: &mut [u8]) -> usize {
+    let digits =
+        _mm256_broadcastsi128_si256(_mm_loadu_si128(LOWER_HEX_DIGITS.as_ptr() as *const __m128i));
+    let mask = _mm256_set1_epi8(0x0F);
+    let blocks = bytes.chunks_exact(32).zip(hex.chunks_exact_mut(64));
+    let mut encoded = 0;
+    for (input, output) in blocks {
+        let input = _mm256_loadu_si256(input.as_ptr() as *const __m256i);
+        let high = _mm256_shuffle_epi8(digits, _mm256_and_si256(_mm256_srli_epi16(input, 4), mask));
+        let low = _mm256_shuffle_epi8(digits, _mm256_and_si256(input, mask));
+        // the unpacks interleave within each 128 bit lane, so the lanes are reordered when storing
+        let (first, second) = (
+            _mm256_unpacklo_epi8(high, low),
+            _mm256_unpackhi_epi8(high, low),
+        );
+        let output = output.as_mut_ptr() as *mut __m256i;
+        _mm256_storeu_si256(output, _mm256_permute2x128_si256(first, second, 0x20));
+        _mm256_storeu_si256(
+            output.add(1),
+            _mm256_permute2x128_si256(first, second, 0x31),
+        );
+        encoded += 32;
+    }
+    encoded
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+unsafe fn hex_encode_neon(bytes: &[u8], hex: &mut [u8]) -> usize {
+    let digits = vld1q_u8(LOWER_HEX_DIGITS.as_ptr());
+    let mask = vdupq_n_u8(0x0F);
+    let blocks = bytes.chunks_exact(16).zip(hex.chunks_exact_mut(32));
+    let mut encoded = 0;
+    for (input, output) in blocks {
+        let input = vld1q_u8(input.as_ptr());
+        let high = vqtbl1q_u8(digits, vshrq_n_u8::<4>(input));
+        let low = vqtbl1q_u8(digits, vandq_u8(input, mask));
+        vst2q_u8(output.as_mut_ptr(), uint8x16x2_t(high, low));
+        encoded += 16;
+    }
+    encoded
+}
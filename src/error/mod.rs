@@ -0,0 +1,110 @@
+//! Provides functions for formatting errors
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert an error, including the chain of errors that caused it, into a String for a log line?
+//!
+//! ## Use
+//!
+//! * result.map_err(|e| error_chain(&e))
+//! * iter.map(error_chain_pretty)
+//!
+//! The functions take the error by reference, so `&dyn Error` and the contents of a `Box<dyn Error + Send + Sync>`
+//! can be passed as well as concrete error types.
+//!
+use std::error::Error;
+
+/// Iterate over the messages of an error followed by each of its sources
+///
+/// The error itself may be unsized, e.g.: `dyn Error + Send + Sync`, so it cannot be coerced to `&dyn Error`,
+/// its sources are always `&dyn Error`.
+fn chain<E: Error + ?Sized>(err: &E) -> impl Iterator<Item = String> + '_ {
+    std::iter::once(err.to_string())
+        .chain(std::iter::successors(err.source(), |&e| e.source()).map(|e| e.to_string()))
+}
+
+/// Convert an error and each of its [`Error::source`]s into a single line String separated by ": "
+///
+/// Use .map_err(|e| error_chain(&e))
+///
+/// Accepts a reference to any error, including `&dyn Error`, pass a boxed error with `boxed.as_ref()`
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::{error::Error, fmt, io};
+///
+/// #[derive(Debug)]
+/// struct ConfigError(io::Error);
+///
+/// impl fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "failed to open config")
+///     }
+/// }
+///
+/// impl Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// let err = ConfigError(io::Error::from_raw_os_error(13));
+/// let result = error_chain(&err);
+///# #[cfg(target_os = "linux")]
+/// assert_eq!(result, "failed to open config: Permission denied (os error 13)");
+///
+/// let results: Vec<Result<u32, ConfigError>> = vec![Ok(1), Err(err)];
+/// let logged: Vec<_> = results.into_iter().map(|r| r.map_err(|e| error_chain(&e))).collect();
+///# assert!(logged[1].as_ref().unwrap_err().starts_with("failed to open config: "));
+///
+/// let boxed: Box<dyn Error + Send + Sync> = Box::new(ConfigError(io::Error::from_raw_os_error(13)));
+/// let result = error_chain(boxed.as_ref());
+///# #[cfg(target_os = "linux")]
+/// assert_eq!(result, "failed to open config: Permission denied (os error 13)");
+///# let errors: Vec<Box<dyn Error>> = vec!["a".into(), "b".into()];
+///# let logged: Vec<_> = errors.iter().map(|e| error_chain(e.as_ref())).collect();
+///# assert_eq!(logged, vec!["a", "b"]);
+/// ```
+pub fn error_chain<E: Error + ?Sized>(err: &E) -> String {
+    chain(err).collect::<Vec<_>>().join(": ")
+}
+
+/// Convert an error and each of its [`Error::source`]s into a multi line String,
+/// each source on its own line indented 2 spaces more than the error it caused
+///
+/// Use .map(error_chain_pretty)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::{error::Error, fmt};
+///
+/// #[derive(Debug)]
+/// struct Layer(&'static str, Option<Box<Layer>>);
+///
+/// impl fmt::Display for Layer {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+///
+/// impl Error for Layer {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         self.1.as_deref().map(|e| e as _)
+///     }
+/// }
+///
+/// let err = Layer("request failed", Some(Box::new(Layer("connection reset", Some(Box::new(Layer("timeout", None)))))));
+/// let dyn_err: &dyn Error = &err;
+/// let result = error_chain_pretty(dyn_err);
+/// let expected = "request failed\n  connection reset\n    timeout";
+/// assert_eq!(result, expected);
+/// ```
+pub fn error_chain_pretty<E: Error + ?Sized>(err: &E) -> String {
+    chain(err)
+        .enumerate()
+        .map(|(depth, e)| format!("{:indent$}{}", "", e, indent = depth * 2))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
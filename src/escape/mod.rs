@@ -0,0 +1,231 @@
+//! Provides functions for escaping text so it can be embedded in other formats
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator of Strings into text that is safe to embed in HTML?
+//!
+//! How do I avoid allocating when most of the Strings do not need escaping?
+//!
+//! How do I convert a String into text that is safe to embed in a JSON string?
+//!
+//! How do I convert key value pairs into a percent encoded URL query string, e.g.: `q=rust%20fmt&page=2`,
+//! and parse one back into pairs?
+//!
+//! ## Use
+//!
+//! * iter.map(escape_html)
+//! * iter.map(escape_html_cow)
+//! * iter.map(escape_json)
+//! * query_string(iter_of_key_value)
+//! * parse_query_string(s)
+//!
+use std::borrow::Cow;
+use std::fmt::{Display, Write};
+
+/// The HTML entity for a char that must be escaped, or None if it can be used as is
+fn html_entity(c: char) -> Option<&'static str> {
+    match c {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        '"' => Some("&quot;"),
+        '\'' => Some("&#39;"),
+        _ => None,
+    }
+}
+
+/// Convert a String or &str into a String with the chars that are special in HTML,
+/// `& < > " '`, replaced by entities, so it is safe in both element content and quoted attributes
+///
+/// Use .map(escape_html)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["<b>bold</b>", "Tom & Jerry", "it's \"quoted\""];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(escape_html).collect();
+/// let expected = vec![
+///     "&lt;b&gt;bold&lt;/b&gt;",
+///     "Tom &amp; Jerry",
+///     "it&#39;s &quot;quoted&quot;",
+/// ];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn escape_html<S: AsRef<str>>(s: S) -> String {
+    escape_html_cow(s.as_ref()).into_owned()
+}
+
+/// Convert a &str or &String into HTML escaped text, like [`escape_html`],
+/// but borrowing the input unchanged when there is nothing to escape
+///
+/// Use .map(escape_html_cow) to skip allocating in hot paths where most of the Strings are plain text
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::borrow::Cow;
+/// let vec = vec!["plain".to_string(), "a < b".to_string()];
+/// let escaped: Vec<_> = vec.iter().map(escape_html_cow).collect();
+/// assert!(matches!(escaped[0], Cow::Borrowed("plain")));
+/// assert!(matches!(escaped[1], Cow::Owned(_)));
+/// assert_eq!(escaped, vec!["plain", "a &lt; b"]);
+/// ```
+pub fn escape_html_cow<S: AsRef<str> + ?Sized>(s: &S) -> Cow<'_, str> {
+    let s = s.as_ref();
+    let Some(first) = s.find(|c| html_entity(c).is_some()) else {
+        return Cow::Borrowed(s);
+    };
+    let mut escaped = String::with_capacity(s.len() + 8);
+    escaped.push_str(&s[..first]);
+    for c in s[first..].chars() {
+        match html_entity(c) {
+            Some(entity) => escaped.push_str(entity),
+            None => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Convert a String or &str into text that can be placed between the double quotes of a JSON string,
+/// `"` and `\` are escaped with a backslash and control chars as `\n`, `\t` etc. or `\u001B`
+///
+/// Use .map(escape_json)
+///
+/// Chars outside ASCII are valid in JSON and are not escaped.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["say \"hi\"", "C:\\temp", "line\nbreak", "bell\u{7}", "café"];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(escape_json).collect();
+/// let expected = vec![
+///     r#"say \"hi\""#,
+///     r#"C:\\temp"#,
+///     r#"line\nbreak"#,
+///     r#"bell\u0007"#,
+///     "café",
+/// ];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn escape_json<S: AsRef<str>>(s: S) -> String {
+    let s = s.as_ref();
+    let mut escaped = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            c if c < ' ' || c == '\u{7f}' => {
+                let _ = write!(escaped, "\\u{:04X}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append s to encoded with every byte except the unreserved chars of RFC 3986, `A-Z a-z 0-9 - . _ ~`,
+/// replaced by `%` and two upper case hexadecimal digits
+fn percent_encode_into(encoded: &mut String, s: &str) {
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{:02X}", byte);
+        }
+    }
+}
+
+/// Decode the `%` escapes and `+` spaces of a query string component,
+/// or None if an escape is not two hexadecimal digits or the bytes are not UTF-8
+fn percent_decode(s: &str) -> Option<String> {
+    let mut decoded = Vec::with_capacity(s.len());
+    let mut bytes = s.bytes();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'%' => {
+                let high = char::from(bytes.next()?).to_digit(16)?;
+                let low = char::from(bytes.next()?).to_digit(16)?;
+                decoded.push((high * 16 + low) as u8);
+            }
+            b'+' => decoded.push(b' '),
+            byte => decoded.push(byte),
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Convert key value pairs that implement [`std::fmt::Display`] into a URL query string,
+/// with each key and value percent encoded, joined with `=` and separated by `&`
+///
+/// Every byte except the unreserved chars of RFC 3986, `A-Z a-z 0-9 - . _ ~`, is encoded,
+/// a space is encoded as `%20`, so the result is valid in both URLs and form bodies.
+/// The leading `?` is not included.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let params = vec![("q", "rust fmt"), ("lang", "en&fr"), ("emoji", "🦀")];
+/// assert_eq!(query_string(params), "q=rust%20fmt&lang=en%26fr&emoji=%F0%9F%A6%80");
+///
+/// let mut sorted = std::collections::BTreeMap::new();
+/// sorted.insert("page", 2);
+/// sorted.insert("per_page", 50);
+/// assert_eq!(query_string(&sorted), "page=2&per_page=50");
+/// ```
+pub fn query_string<I, K, V>(iter: I) -> String
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    let mut query = String::new();
+    for (key, value) in iter {
+        if !query.is_empty() {
+            query.push('&');
+        }
+        percent_encode_into(&mut query, &key.to_string());
+        query.push('=');
+        percent_encode_into(&mut query, &value.to_string());
+    }
+    query
+}
+
+/// Parse a URL query string, as produced by [`query_string`], into its decoded key value pairs, in order
+///
+/// A leading `?` is skipped, `+` is decoded as a space, a key without `=` has an empty value and
+/// empty pairs, e.g.: from `&&`, are skipped. Repeated keys are kept, collect into a map to keep the last.
+/// Returns None if a `%` escape is invalid or a decoded key or value is not UTF-8.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let pairs = parse_query_string("?q=rust+fmt&lang=en%26fr&flag&&tag=a&tag=b").unwrap();
+/// let expected = vec![("q", "rust fmt"), ("lang", "en&fr"), ("flag", ""), ("tag", "a"), ("tag", "b")];
+/// let expected: Vec<_> = expected.into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+/// assert_eq!(pairs, expected);
+///
+/// let params = vec![("name", "Zoë & co"), ("path", "/a b?c=d")];
+/// let round_trip = parse_query_string(query_string(params)).unwrap();
+/// assert_eq!(round_trip[1], ("path".to_string(), "/a b?c=d".to_string()));
+///
+/// assert_eq!(parse_query_string("bad=%G1"), None);
+///# assert_eq!(parse_query_string("bad=%F0"), None);
+///# assert_eq!(parse_query_string("bad=%4"), None);
+///# assert_eq!(parse_query_string(""), Some(vec![]));
+/// ```
+pub fn parse_query_string<S: AsRef<str>>(s: S) -> Option<Vec<(String, String)>> {
+    let s = s.as_ref();
+    let s = s.strip_prefix('?').unwrap_or(s);
+    s.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Some((percent_decode(key)?, percent_decode(value)?))
+        })
+        .collect()
+}
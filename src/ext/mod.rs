@@ -0,0 +1,195 @@
+//! Provides extension traits that add the functions of this crate as methods, for method chaining
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a single number into hexadecimal, binary or grouped thousands without a format! string,
+//! e.g.: `42_u8.to_hex()`?
+//!
+//! How do I truncate, center, indent or chunk a &str with method chaining, e.g.: `s.truncate_ellipsis(20)`?
+//!
+//! How do I inspect a byte slice as hexadecimal, a hex dump or base64, e.g.: `packet.hexdump()`?
+//!
+//! ## Use
+//!
+//! * number.to_hex()
+//! * number.to_bin_pad::<8>()
+//! * number.grouped()
+//! * bytes.hex()
+//! * bytes.hexdump()
+//! * s.truncate_ellipsis(20).pad_center(24)
+//!
+//! The methods call the free functions, so `n.to_hex_full()` is the same as `hex_full(n)`,
+//! use the free functions in .map() and the methods for single values.
+//!
+use crate::align::{pad_to, truncate_to, Alignment};
+use crate::encode::{base64, hex_string, hexdump, HexString};
+#[cfg(feature = "itertools")]
+use crate::fmt::chunk_join;
+use crate::fmt::{
+    binary, binary_full, binary_zero_pad, format_exact, hex_full, octal, upper_hex_full, BitWidth,
+};
+use crate::io::indent;
+use crate::num::{group_thousands, group_thousands_with};
+use std::fmt::{Binary, Display, LowerHex, Octal, UpperHex};
+
+/// Formatting methods for the primitive integers
+///
+/// Implemented for all primitive integers.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(42_u8.to_hex(), "2a");
+/// assert_eq!(5_u8.to_bin_pad::<8>(), "00000101");
+/// assert_eq!(1234567.grouped(), "1,234,567");
+///
+/// let status: u16 = 0x1F;
+/// let message = format!("status 0x{} (0b{})", status.to_hex_full(), status.to_bin_full());
+/// assert_eq!(message, "status 0x001f (0b0000000000011111)");
+/// ```
+pub trait I2uNumExt: Copy + Binary + Octal + LowerHex + UpperHex + Display + BitWidth {
+    /// Lower case hexadecimal without padding, short for **format!("{:x}", self)**
+    fn to_hex(self) -> String {
+        format_exact(format_args!("{:x}", self))
+    }
+
+    /// Upper case hexadecimal without padding, short for **format!("{:X}", self)**
+    fn to_upper_hex(self) -> String {
+        format_exact(format_args!("{:X}", self))
+    }
+
+    /// Lower case hexadecimal zero padded to the width of the type, see [`hex_full`]
+    fn to_hex_full(self) -> String {
+        hex_full(self)
+    }
+
+    /// Upper case hexadecimal zero padded to the width of the type, see [`upper_hex_full`]
+    fn to_upper_hex_full(self) -> String {
+        upper_hex_full(self)
+    }
+
+    /// Binary without padding, see [`binary`]
+    fn to_bin(self) -> String {
+        binary(self)
+    }
+
+    /// Binary zero padded to at least N digits, see [`binary_zero_pad`]
+    fn to_bin_pad<const N: usize>(self) -> String {
+        binary_zero_pad::<N, _>(self)
+    }
+
+    /// Binary zero padded to the width of the type, see [`binary_full`]
+    fn to_bin_full(self) -> String {
+        binary_full(self)
+    }
+
+    /// Octal without padding, see [`octal`]
+    fn to_oct(self) -> String {
+        octal(self)
+    }
+
+    /// Digits grouped in thousands separated by commas, see [`group_thousands`]
+    fn grouped(self) -> String {
+        group_thousands(self)
+    }
+
+    /// Digits grouped in thousands separated by separator, see [`group_thousands_with`]
+    fn grouped_with<S: AsRef<str>>(self, separator: S) -> String {
+        group_thousands_with(self, separator)
+    }
+}
+
+macro_rules! impl_num_ext {
+    ($($t:ty),*) => {
+        $(impl I2uNumExt for $t {})*
+    };
+}
+
+impl_num_ext!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Encoding methods for byte slices, and anything that derefs to one, e.g.: `Vec<u8>` or `[u8; N]`
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let packet = vec![0xCA_u8, 0xFE, 0x00, 0x2A];
+/// assert_eq!(packet.hex(), "cafe002a");
+/// assert_eq!(packet.hex_upper(), "CAFE002A");
+/// assert_eq!(b"user:pass".base64(), "dXNlcjpwYXNz");
+/// assert_eq!(b"hi\n".hexdump(), "00000000: 6869 0a                                  hi.\n");
+/// ```
+pub trait I2uBytesExt {
+    /// Lower case hexadecimal, two digits per byte, see [`hex_string`]
+    fn hex(&self) -> String;
+    /// Upper case hexadecimal, two digits per byte
+    fn hex_upper(&self) -> String;
+    /// An xxd style hex dump, 16 bytes per line, see [`hexdump`]
+    fn hexdump(&self) -> String;
+    /// Base64 with the standard alphabet and padding, see [`base64`]
+    fn base64(&self) -> String;
+}
+
+impl I2uBytesExt for [u8] {
+    fn hex(&self) -> String {
+        hex_string(self)
+    }
+
+    fn hex_upper(&self) -> String {
+        self.iter().collect::<HexString>().uppercase().to_string()
+    }
+
+    fn hexdump(&self) -> String {
+        hexdump(self)
+    }
+
+    fn base64(&self) -> String {
+        base64(self)
+    }
+}
+
+/// Text methods for string slices, and anything that derefs to one, e.g.: `String`
+///
+/// The widths are runtime values, unlike the const generic widths of the free functions,
+/// with the `unicode-width` feature wide chars are counted as 2 columns.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let title = "a title that is much too long";
+/// assert_eq!(title.truncate_ellipsis(12).pad_center(16), "  a title tha…  ");
+/// assert_eq!("line 1\nline 2".indent("> "), "> line 1\n> line 2");
+///# #[cfg(feature = "itertools")]
+/// assert_eq!("C0FFEE".chunk_join(2, ":"), "C0:FF:EE");
+/// ```
+pub trait I2uStrExt {
+    /// Split into chunks of chunk_size chars joined with separator, see [`crate::fmt::chunk_join`]
+    #[cfg(feature = "itertools")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "itertools")))]
+    fn chunk_join<R: AsRef<str>>(&self, chunk_size: usize, separator: R) -> String;
+    /// Truncate to at most width columns, ending with … if it was too long,
+    /// see [`crate::align::truncate_ellipsis`]
+    fn truncate_ellipsis(&self, width: usize) -> String;
+    /// Prefix every non empty line with prefix, see [`indent`]
+    fn indent<P: AsRef<str>>(&self, prefix: P) -> String;
+    /// Center in width columns, any odd space goes on the right, see [`crate::align::pad_center`]
+    fn pad_center(&self, width: usize) -> String;
+}
+
+impl I2uStrExt for str {
+    #[cfg(feature = "itertools")]
+    fn chunk_join<R: AsRef<str>>(&self, chunk_size: usize, separator: R) -> String {
+        chunk_join(self, chunk_size, separator)
+    }
+
+    fn truncate_ellipsis(&self, width: usize) -> String {
+        truncate_to(self, width)
+    }
+
+    fn indent<P: AsRef<str>>(&self, prefix: P) -> String {
+        indent(self, prefix)
+    }
+
+    fn pad_center(&self, width: usize) -> String {
+        pad_to(self, width, Alignment::Center)
+    }
+}
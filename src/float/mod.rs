@@ -0,0 +1,614 @@
+//! Provides functions for formatting floats exactly, as their binary representation, or rounded
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a float into a String with up to N decimal places, without trailing zeros, e.g.: `1.5` not `1.50`?
+//!
+//! How do I convert a float into scientific notation with a fixed number of digits and a signed two digit
+//! exponent, e.g.: `1.2345e+07` like C's `%e`, for CSV or other data export?
+//!
+//! How do I convert a float into scientific notation with a Unicode superscript exponent, e.g.: `1.5×10³`,
+//! for reports and plot labels?
+//!
+//! How do I convert a float into a String with P decimal places rounded half up, e.g.: for money,
+//! when `{:.2}` rounds half to even?
+//!
+//! How do I convert a vector or iterator of floats into a String that represents each value exactly,
+//! like `%a` in C's printf, e.g.: `0x1.91eb851eb851fp+1`?
+//!
+//! How do I show the sign, exponent and fraction bits of a float, to debug a precision issue?
+//!
+//! How do I log or store the exact bit pattern of a float as hexadecimal, and read it back?
+//!
+//! How do I parse a C99 hexadecimal float, e.g.: from a test vector or C source, back into an f64?
+//!
+//! ## Use
+//!
+//! * iter.map(hex_float)
+//! * iter.filter_map(parse_hex_float)
+//! * iter.map(float_bits)
+//! * iter.map(float_to_bits_hex)
+//! * iter.filter_map(bits_hex_to_float::<f32, _>)
+//! * iter.map(|f| fixed::<2, _>(f, RoundingMode::HalfUp))
+//! * iter.map(|f| float_trimmed(f, 3))
+//! * iter.map(scientific::<4, _>)
+//! * iter.map(scientific_unicode)
+//! * iter.map(|f| scientific_unicode_with(f, ExponentStyle::Caret))
+//!
+use crate::cast::ToF64;
+
+/// The number of bits in the fraction of an f64, excluding the implicit leading bit
+const F64_FRACTION_BITS: u32 = 52;
+/// The exponent bias of an f64
+const F64_EXPONENT_BIAS: i64 = 1023;
+/// The exponent of the smallest normal f64
+const F64_MIN_EXPONENT: i64 = 1 - F64_EXPONENT_BIAS;
+
+/// IEEE-754 binary floating point types, used to show the fields of their bit pattern
+///
+/// Implemented for f32, f64 and references to them.
+pub trait FloatBits {
+    /// The number of bits in the biased exponent, e.g.: 8 for f32
+    const EXPONENT_BITS: usize;
+    /// The number of bits in the fraction, excluding the implicit leading bit, e.g.: 23 for f32
+    const FRACTION_BITS: usize;
+    /// The bit pattern of the float, see [`f32::to_bits`], widened to a u64
+    fn bits(&self) -> u64;
+}
+
+/// IEEE-754 binary floating point types that can be created from their bit pattern
+///
+/// Implemented for f32 and f64.
+pub trait FromFloatBits: FloatBits + Sized {
+    /// The float with the bit pattern, see [`f32::from_bits`], the bits must fit in the type
+    fn from_bits(bits: u64) -> Self;
+}
+
+macro_rules! impl_float_bits {
+    ($($t:ty, $exponent_bits:expr, $u:ty);*) => {
+        $(impl FloatBits for $t {
+            const EXPONENT_BITS: usize = $exponent_bits;
+            const FRACTION_BITS: usize = <$t>::MANTISSA_DIGITS as usize - 1;
+            fn bits(&self) -> u64 {
+                u64::from(self.to_bits())
+            }
+        }
+
+        impl FromFloatBits for $t {
+            fn from_bits(bits: u64) -> Self {
+                <$t>::from_bits(bits as $u)
+            }
+        })*
+    };
+}
+
+impl_float_bits!(f32, 8, u32; f64, 11, u64);
+
+impl<T: FloatBits + ?Sized> FloatBits for &T {
+    const EXPONENT_BITS: usize = T::EXPONENT_BITS;
+    const FRACTION_BITS: usize = T::FRACTION_BITS;
+    fn bits(&self) -> u64 {
+        (**self).bits()
+    }
+}
+
+/// Convert a float into its sign, biased exponent and fraction bits separated by ` | `,
+/// followed by the decoded exponent, i.e.: the power of 2 the value is scaled by
+///
+/// Use .map(float_bits)
+///
+/// The decoded exponent is the biased exponent minus the bias, 127 for f32 and 1023 for f64,
+/// zero, subnormals, infinity and NaN are labelled, as their exponent field has a special meaning.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(float_bits(3.14_f32), "0 | 10000000 | 10010001111010111000011 (exponent +1)");
+/// assert_eq!(float_bits(-0.1_f32), "1 | 01111011 | 10011001100110011001101 (exponent -4)");
+///
+/// let vec = vec![0.0_f32, f32::MIN_POSITIVE / 2.0, f32::INFINITY, f32::NAN];
+/// let vec_to_strings: Vec<_> = vec.iter().map(float_bits).collect();
+/// let expected = vec![
+///     "0 | 00000000 | 00000000000000000000000 (zero)",
+///     "0 | 00000000 | 10000000000000000000000 (subnormal, exponent -126)",
+///     "0 | 11111111 | 00000000000000000000000 (infinity)",
+///     "0 | 11111111 | 10000000000000000000000 (NaN)",
+/// ];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// let result = float_bits(1.0_f64);
+/// assert_eq!(result, format!("0 | 01111111111 | {} (exponent +0)", "0".repeat(52)));
+/// ```
+pub fn float_bits<F: FloatBits>(f: F) -> String {
+    let bits = f.bits();
+    let (exponent_bits, fraction_bits) = (F::EXPONENT_BITS, F::FRACTION_BITS);
+    let exponent_max = (1 << exponent_bits) - 1;
+    let bias = exponent_max >> 1;
+    let sign = bits >> (exponent_bits + fraction_bits) & 1;
+    let exponent = bits >> fraction_bits & exponent_max;
+    let fraction = bits & ((1 << fraction_bits) - 1);
+    let decoded = match (exponent, fraction) {
+        (0, 0) => "zero".to_string(),
+        (0, _) => format!("subnormal, exponent {:+}", 1 - bias as i64),
+        (e, 0) if e == exponent_max => "infinity".to_string(),
+        (e, _) if e == exponent_max => "NaN".to_string(),
+        (e, _) => format!("exponent {:+}", e as i64 - bias as i64),
+    };
+    format!(
+        "{} | {:0ew$b} | {:0fw$b} ({})",
+        sign,
+        exponent,
+        fraction,
+        decoded,
+        ew = exponent_bits,
+        fw = fraction_bits
+    )
+}
+
+/// Convert a float into its bit pattern as a lower case hexadecimal String,
+/// zero padded to the number of nibbles in its type
+///
+/// Use .map(float_to_bits_hex)
+///
+/// short for **format!("{:08x}", f.to_bits())** for f32 and **format!("{:016x}", f.to_bits())** for f64,
+/// the bit pattern represents the value exactly, including the sign of zero and the payload of NaN.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![3.14159_f32, -0.0, 1.0];
+/// let vec_to_strings: Vec<_> = vec.iter().map(float_to_bits_hex).collect();
+/// assert_eq!(vec_to_strings, vec!["40490fd0", "80000000", "3f800000"]);
+///
+/// assert_eq!(float_to_bits_hex(0.1_f64), "3fb999999999999a");
+/// ```
+pub fn float_to_bits_hex<F: FloatBits>(f: F) -> String {
+    let width = (1 + F::EXPONENT_BITS + F::FRACTION_BITS) / 4;
+    format!("{:0width$x}", f.bits(), width = width)
+}
+
+/// Parse the hexadecimal bit pattern of a float, as produced by [`float_to_bits_hex`], into an f32 or f64
+///
+/// The digits are in either case with an optional `0x` prefix, leading zeros can be omitted.
+/// Returns None if the String is not hexadecimal or has more bits than the type,
+/// so can be used in .filter_map(bits_hex_to_float::<f64, _>)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(bits_hex_to_float::<f32, _>("40490fd0"), Some(3.14159));
+/// assert_eq!(bits_hex_to_float::<f64, _>("0x3FB999999999999A"), Some(0.1));
+/// assert_eq!(bits_hex_to_float::<f32, _>("3fb999999999999a"), None);
+///
+/// let logged = vec!["3ff0000000000000", "not hex", "c000000000000000"];
+/// let parsed: Vec<f64> = logged.into_iter().filter_map(bits_hex_to_float).collect();
+/// assert_eq!(parsed, vec![1.0, -2.0]);
+///
+/// let nan = f64::from_bits(0x7ff8_0000_dead_beef);
+/// let round_trip: f64 = bits_hex_to_float(float_to_bits_hex(nan)).unwrap();
+/// assert_eq!(round_trip.to_bits(), nan.to_bits());
+///# assert_eq!(bits_hex_to_float::<f32, _>(""), None);
+///# assert_eq!(bits_hex_to_float::<f32, _>("0x"), None);
+///# assert_eq!(bits_hex_to_float::<f32, _>("+1"), None);
+///# assert_eq!(bits_hex_to_float::<f32, _>("100000000"), None);
+/// ```
+pub fn bits_hex_to_float<F: FromFloatBits, S: AsRef<str>>(s: S) -> Option<F> {
+    let s = s.as_ref();
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let bits = u64::from_str_radix(digits, 16).ok()?;
+    let total_bits = 1 + F::EXPONENT_BITS + F::FRACTION_BITS;
+    if total_bits < 64 && bits >> total_bits != 0 {
+        return None;
+    }
+    Some(F::from_bits(bits))
+}
+
+/// Convert a float into a C99 hexadecimal float String, like `%a` in printf
+///
+/// Use .map(hex_float)
+///
+/// The digits after the point are the fraction of the float, without trailing zeros, and the exponent
+/// is a power of 2, so the output is exact, unlike decimal output, which is rounded.
+/// Subnormals are shown with a leading `0x0.` and the exponent `-1022`,
+/// infinity and NaN are shown as `inf`, `-inf` and `nan`, like glibc. An f32 is shown as the same f64.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![3.14, 1.0, -0.5, 0.1, 0.0];
+/// let vec_to_strings: Vec<_> = vec.iter().map(hex_float).collect();
+/// let expected = vec!["0x1.91eb851eb851fp+1", "0x1p+0", "-0x1p-1", "0x1.999999999999ap-4", "0x0p+0"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(hex_float(f64::MIN_POSITIVE / 4.0), "0x0.4p-1022");
+/// assert_eq!(hex_float(f64::NEG_INFINITY), "-inf");
+/// assert_eq!(hex_float(0.1_f32), "0x1.99999ap-4");
+/// ```
+pub fn hex_float<F: ToF64>(f: F) -> String {
+    let f = f.to_f64();
+    let sign = if f.is_sign_negative() { "-" } else { "" };
+    if f.is_nan() {
+        return "nan".to_string();
+    }
+    if f.is_infinite() {
+        return format!("{}inf", sign);
+    }
+    let bits = f.to_bits();
+    let biased_exponent = ((bits >> F64_FRACTION_BITS) & 0x7FF) as i64;
+    let fraction = bits & ((1 << F64_FRACTION_BITS) - 1);
+    let (lead, exponent) = match (biased_exponent, fraction) {
+        (0, 0) => (0, 0),
+        (0, _) => (0, F64_MIN_EXPONENT),
+        _ => (1, biased_exponent - F64_EXPONENT_BIAS),
+    };
+    let digits = format!("{:013x}", fraction);
+    let digits = digits.trim_end_matches('0');
+    let point = if digits.is_empty() { "" } else { "." };
+    format!("{}0x{}{}{}p{:+}", sign, lead, point, digits, exponent)
+}
+
+/// Parse a C99 hexadecimal float String, as produced by [`hex_float`] or printf's `%a`, into an f64
+///
+/// The `0x` prefix is required, the point, fraction digits and the `p` exponent are optional,
+/// `inf`, `infinity` and `nan` are accepted in any case. Values that cannot be represented exactly,
+/// i.e.: with more than 53 significant bits, are rounded to the nearest f64, ties to even.
+/// Returns None if the String cannot be parsed, so can be used in .filter_map(parse_hex_float)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["0x1.91eb851eb851fp+1", "-0x1p-1", "0XAp0", "0x.8", "3.14", "inf"];
+/// let parsed: Vec<_> = vec.into_iter().filter_map(parse_hex_float).collect();
+/// assert_eq!(parsed, vec![3.14, -0.5, 10.0, 0.5, f64::INFINITY]);
+///
+/// for f in [0.1, -1e300, 5e-324, f64::MAX] {
+///     assert_eq!(parse_hex_float(hex_float(f)), Some(f));
+/// }
+/// // 1 + 2^-53 is halfway between 1 and the next f64, so rounds to even
+/// assert_eq!(parse_hex_float("0x1.00000000000008p0"), Some(1.0));
+///# assert_eq!(parse_hex_float("0x1.000000000000080001p0"), Some(1.0 + f64::EPSILON));
+///# assert_eq!(parse_hex_float("0x1.00000000000018p0"), Some(1.0 + 2.0 * f64::EPSILON));
+///# assert_eq!(parse_hex_float("0x1.fffffffffffff8p1023"), Some(f64::INFINITY));
+///# assert_eq!(parse_hex_float("0x1p-1075"), Some(0.0));
+///# assert_eq!(parse_hex_float("0x1.0001p-1075"), Some(5e-324));
+///# assert_eq!(parse_hex_float("0x0.0000000000001p-1022"), Some(5e-324));
+///# assert_eq!(parse_hex_float("0x1p+99999999999999999999"), Some(f64::INFINITY));
+///# assert_eq!(parse_hex_float("0x1p-99999999999999999999"), Some(0.0));
+///# assert!(parse_hex_float("-0x0p+0").unwrap().is_sign_negative());
+///# assert!(parse_hex_float("NaN").unwrap().is_nan());
+///# for s in ["", "0x", "0x.", "0x1p", "0x1p+", "0x1.g", "0x1 ", "0x1p1.5"] {
+///#     assert_eq!(parse_hex_float(s), None, "{}", s);
+///# }
+/// ```
+pub fn parse_hex_float<S: AsRef<str>>(s: S) -> Option<f64> {
+    let s = s.as_ref();
+    let (negative, rest) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let sign = if negative { -1.0 } else { 1.0 };
+    if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+        return Some(sign * f64::INFINITY);
+    }
+    if rest.eq_ignore_ascii_case("nan") {
+        return Some(f64::NAN);
+    }
+    let rest = rest
+        .strip_prefix("0x")
+        .or_else(|| rest.strip_prefix("0X"))?;
+    let (digits, exponent) = match rest.find(['p', 'P']) {
+        Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+        None => (rest, None),
+    };
+    let (integral, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if integral.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    // value = mantissa * 2^exponent, the digits that do not fit in the mantissa only affect rounding
+    let mut mantissa: u64 = 0;
+    let mut binary_exponent: i64 = match exponent {
+        Some(exponent) => parse_exponent(exponent)?,
+        None => 0,
+    };
+    let mut sticky = false;
+    for (digit, is_fraction) in integral
+        .chars()
+        .map(|c| (c, false))
+        .chain(fraction.chars().map(|c| (c, true)))
+    {
+        let digit = digit.to_digit(16)? as u64;
+        if mantissa < 1 << 60 {
+            mantissa = mantissa * 16 + digit;
+            if is_fraction {
+                binary_exponent -= 4;
+            }
+        } else {
+            sticky |= digit != 0;
+            if !is_fraction {
+                binary_exponent += 4;
+            }
+        }
+    }
+    Some(sign * round_to_f64(mantissa, binary_exponent, sticky))
+}
+
+/// Parse the decimal exponent after the `p` of a hexadecimal float,
+/// saturating exponents far outside the range of an f64
+fn parse_exponent(exponent: &str) -> Option<i64> {
+    let (negative, digits) = match exponent.as_bytes().first() {
+        Some(b'-') => (true, &exponent[1..]),
+        Some(b'+') => (false, &exponent[1..]),
+        _ => (false, exponent),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut value: i64 = 0;
+    for c in digits.chars() {
+        value = (value * 10 + c.to_digit(10)? as i64).min(1 << 32);
+    }
+    Some(if negative { -value } else { value })
+}
+
+/// Round mantissa * 2^exponent to the nearest f64, ties to even,
+/// sticky is true if non zero bits below the mantissa were discarded
+fn round_to_f64(mantissa: u64, exponent: i64, sticky: bool) -> f64 {
+    if mantissa == 0 {
+        return 0.0;
+    }
+    let leading_zeros = mantissa.leading_zeros();
+    let mantissa = mantissa << leading_zeros;
+    let exponent = exponent - leading_zeros as i64;
+    // the exponent of the leading bit, the number of bits an f64 keeps and the exponent of the last kept bit
+    let leading_exponent = exponent + 63;
+    if leading_exponent > F64_EXPONENT_BIAS {
+        return f64::INFINITY;
+    }
+    let kept = (F64_FRACTION_BITS as i64 + 1)
+        .min(leading_exponent - F64_MIN_EXPONENT + F64_FRACTION_BITS as i64 + 1);
+    if kept < 0 {
+        return 0.0;
+    }
+    let shift = 64 - kept as u32;
+    let mantissa = u128::from(mantissa);
+    let mut rounded = mantissa >> shift;
+    let remainder = mantissa & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || (remainder == half && (sticky || rounded & 1 == 1)) {
+        rounded += 1;
+    }
+    // rounded has at most 54 bits, so converts exactly, and the product is exact unless it overflows
+    (rounded as f64) * power_of_two(exponent + shift as i64)
+}
+
+/// 2^exponent as an f64, for exponents from -1074 to 1023
+fn power_of_two(exponent: i64) -> f64 {
+    if exponent >= F64_MIN_EXPONENT {
+        f64::from_bits(((exponent + F64_EXPONENT_BIAS) as u64) << F64_FRACTION_BITS)
+    } else {
+        f64::from_bits(1 << (exponent - F64_MIN_EXPONENT + F64_FRACTION_BITS as i64))
+    }
+}
+
+/// How a value exactly halfway between two results is rounded, values closer to one are rounded to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Round ties away from zero, e.g.: 2.5 to 3 and -2.5 to -3, as taught in school and used for money
+    HalfUp,
+    /// Round ties towards zero, e.g.: 2.5 to 2 and -2.5 to -2
+    HalfDown,
+    /// Round ties to the even neighbour, e.g.: 2.5 to 2 and 3.5 to 4, also known as banker's rounding,
+    /// as used by **format!("{:.P$}", f)**
+    #[default]
+    HalfEven,
+}
+
+/// Convert a float into a String with P digits after the decimal point, rounded with mode
+///
+/// Use .map(|f| fixed::<2, _>(f, RoundingMode::HalfUp))
+///
+/// The float is rounded as the shortest decimal that parses back to it, i.e.: as written in source code,
+/// so 2.675, which is stored as 2.67499999999999982236431605997495353221893310546875, is a tie and
+/// rounds half up to 2.68. **format!("{:.2}", 2.675)** rounds the stored value and gives 2.67.
+/// Infinity and NaN are shown as `inf`, `-inf` and `NaN`, like Display.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let prices = vec![0.125, 2.675, 1.005, -0.5];
+/// let half_up: Vec<_> = prices.iter().map(|f| fixed::<2, _>(f, RoundingMode::HalfUp)).collect();
+/// assert_eq!(half_up, vec!["0.13", "2.68", "1.01", "-0.50"]);
+///
+/// let vec = vec![0.5, 1.5, 2.5, -2.5, 2.51];
+/// let half_even: Vec<_> = vec.iter().map(|f| fixed::<0, _>(f, RoundingMode::HalfEven)).collect();
+/// assert_eq!(half_even, vec!["0", "2", "2", "-2", "3"]);
+/// let half_down: Vec<_> = vec.iter().map(|f| fixed::<0, _>(f, RoundingMode::HalfDown)).collect();
+/// assert_eq!(half_down, vec!["0", "1", "2", "-2", "3"]);
+///
+/// assert_eq!(fixed::<1, _>(9.95, RoundingMode::HalfUp), "10.0");
+///# assert_eq!(fixed::<3, _>(1.5, RoundingMode::HalfUp), "1.500");
+///# assert_eq!(fixed::<2, _>(1e-10, RoundingMode::HalfUp), "0.00");
+///# assert_eq!(fixed::<2, _>(-0.004, RoundingMode::HalfUp), "-0.00");
+///# assert_eq!(fixed::<2, _>(f64::NAN, RoundingMode::HalfUp), "NaN");
+///# assert_eq!(fixed::<0, _>(99.5, RoundingMode::HalfUp), "100");
+///# assert_eq!(fixed::<1, _>(0.05, RoundingMode::HalfEven), "0.0");
+///# assert_eq!(fixed::<1, _>(0.0500001, RoundingMode::HalfDown), "0.1");
+/// ```
+pub fn fixed<const P: usize, F: ToF64>(f: F, mode: RoundingMode) -> String {
+    let f = f.to_f64();
+    if !f.is_finite() {
+        return f.to_string();
+    }
+    let sign = if f.is_sign_negative() { "-" } else { "" };
+    let shortest = f.abs().to_string();
+    let (integral, fraction) = shortest.split_once('.').unwrap_or((&shortest, ""));
+    let mut digits: Vec<u8> = integral.bytes().collect();
+    let fraction = fraction.as_bytes();
+    digits.extend((0..P).map(|i| fraction.get(i).copied().unwrap_or(b'0')));
+    let round_up = match fraction.get(P) {
+        Some(&next) if next > b'5' => true,
+        Some(&b'5') if fraction[P + 1..].iter().any(|&d| d != b'0') => true,
+        Some(&b'5') => match mode {
+            RoundingMode::HalfUp => true,
+            RoundingMode::HalfDown => false,
+            RoundingMode::HalfEven => digits.last().is_some_and(|d| (d - b'0') % 2 == 1),
+        },
+        _ => false,
+    };
+    if round_up {
+        let carry = digits.iter_mut().rev().all(|d| {
+            *d = if *d == b'9' { b'0' } else { *d + 1 };
+            *d == b'0'
+        });
+        if carry {
+            digits.insert(0, b'1');
+        }
+    }
+    let point = digits.len() - P;
+    let digits: String = digits.into_iter().map(char::from).collect();
+    match P {
+        0 => format!("{}{}", sign, digits),
+        _ => format!("{}{}.{}", sign, &digits[..point], &digits[point..]),
+    }
+}
+
+/// Convert a float into a String with at most max_precision digits after the decimal point,
+/// without trailing zeros or a trailing decimal point
+///
+/// Use .map(|f| float_trimmed(f, 2))
+///
+/// short for **format!("{:.2}", f)** with the trailing zeros removed, e.g.: `1.50` becomes `1.5`
+/// and `2.00` becomes `2`, a value that rounds to zero is shown as `0`, without a minus sign.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1.5, 2.0, 3.14159, 100.0, 0.001, -0.001];
+/// let vec_to_strings: Vec<_> = vec.iter().map(|f| float_trimmed(f, 2)).collect();
+/// assert_eq!(vec_to_strings, vec!["1.5", "2", "3.14", "100", "0", "0"]);
+///
+/// assert_eq!(float_trimmed(-2.50_f32, 3), "-2.5");
+/// assert_eq!(float_trimmed(1.999, 2), "2");
+///# assert_eq!(float_trimmed(f64::INFINITY, 2), "inf");
+///# assert_eq!(float_trimmed(10.0, 0), "10");
+/// ```
+pub fn float_trimmed<F: ToF64>(f: F, max_precision: usize) -> String {
+    let formatted = format!("{:.*}", max_precision, f.to_f64());
+    let trimmed = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &formatted
+    };
+    match trimmed {
+        "-0" => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/// Convert a float into normalized scientific notation, one digit before the decimal point,
+/// exactly P digits after it and an exponent with a sign and at least two digits, e.g.: `1.2345e+07`
+///
+/// Use .map(scientific::<4, _>)
+///
+/// Like **format!("{:.4e}", f)**, which gives `1.2345e7`, but with the exponent formatted like `%e` in C,
+/// Python and spreadsheets, so the output has the same width for exponents from -99 to 99.
+/// Infinity and NaN are shown as `inf`, `-inf` and `NaN`, like Display.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![12345678.0, 0.000123, -1.0, 0.0, 1e100];
+/// let vec_to_strings: Vec<_> = vec.iter().map(scientific::<4, _>).collect();
+/// let expected = vec!["1.2346e+07", "1.2300e-04", "-1.0000e+00", "0.0000e+00", "1.0000e+100"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(scientific::<2, _>(9.999), "1.00e+01");
+/// assert_eq!(scientific::<0, _>(5e-324), "5e-324");
+///# assert_eq!(scientific::<2, _>(f64::NEG_INFINITY), "-inf");
+///# assert_eq!(scientific::<1, _>(0.25_f32), "2.5e-01");
+/// ```
+pub fn scientific<const P: usize, F: ToF64>(f: F) -> String {
+    let formatted = format!("{:.*e}", P, f.to_f64());
+    match formatted.split_once('e') {
+        Some((mantissa, exponent)) => {
+            let exponent: i32 = exponent
+                .parse()
+                .expect("Rust formats the exponent as an integer");
+            format!("{}e{:+03}", mantissa, exponent)
+        }
+        None => formatted,
+    }
+}
+
+/// How [`scientific_unicode_with`] writes the power of ten
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ExponentStyle {
+    /// Unicode superscript digits, e.g.: `1.5×10³`
+    #[default]
+    Superscript,
+    /// A caret, e.g.: `1.5×10^3`, for fonts without superscript digits or for LaTeX like plot labels
+    Caret,
+}
+
+/// The Unicode superscript digits, indexed by digit
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// Convert a float into scientific notation with `×10` and a Unicode superscript exponent, e.g.: `1.5×10³`
+///
+/// Use .map(scientific_unicode)
+///
+/// short for **scientific_unicode_with(f, ExponentStyle::Superscript)**,
+/// the mantissa has the fewest digits that represent the value exactly, like **format!("{:e}", f)**.
+/// Infinity and NaN are shown as `inf`, `-inf` and `NaN`, like Display.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1500.0, 0.00025, -6.02214076e23, 1.0];
+/// let vec_to_strings: Vec<_> = vec.iter().map(scientific_unicode).collect();
+/// assert_eq!(vec_to_strings, vec!["1.5×10³", "2.5×10⁻⁴", "-6.02214076×10²³", "1×10⁰"]);
+///# assert_eq!(scientific_unicode(f64::INFINITY), "inf");
+///# assert_eq!(scientific_unicode(0.0), "0×10⁰");
+/// ```
+pub fn scientific_unicode<F: ToF64>(f: F) -> String {
+    scientific_unicode_with(f, ExponentStyle::Superscript)
+}
+
+/// Convert a float into scientific notation with `×10` and the exponent written in style,
+/// e.g.: `1.5×10³` or `1.5×10^3`
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1500.0, 0.00025];
+/// let vec_to_strings: Vec<_> = vec.iter().map(|f| scientific_unicode_with(f, ExponentStyle::Caret)).collect();
+/// assert_eq!(vec_to_strings, vec!["1.5×10^3", "2.5×10^-4"]);
+///
+/// assert_eq!(scientific_unicode_with(1e-10, ExponentStyle::Superscript), "1×10⁻¹⁰");
+/// ```
+pub fn scientific_unicode_with<F: ToF64>(f: F, style: ExponentStyle) -> String {
+    let formatted = format!("{:e}", f.to_f64());
+    let Some((mantissa, exponent)) = formatted.split_once('e') else {
+        return formatted;
+    };
+    match style {
+        ExponentStyle::Caret => format!("{}×10^{}", mantissa, exponent),
+        ExponentStyle::Superscript => {
+            let mut scientific = String::with_capacity(mantissa.len() + 5 + 3 * exponent.len());
+            scientific.push_str(mantissa);
+            scientific.push_str("×10");
+            scientific.extend(exponent.chars().map(|c| match c.to_digit(10) {
+                Some(digit) => SUPERSCRIPT_DIGITS[digit as usize],
+                None => '⁻',
+            }));
+            scientific
+        }
+    }
+}
@@ -10,15 +10,85 @@
 //! * space padded upper case hexadecimal.
 //! * leading zero padded lower case hexadecimal.
 //! * space padded lower case hexadecimal.
+//! * binary or hexadecimal zero padded to the full width of the integer type.
+//! * hexadecimal of the bytes in an explicit big or little endian order.
+//! * hexadecimal byte pairs separated by spaces, e.g.: `DE AD BE EF`.
+//! * sign and magnitude hexadecimal or binary, e.g.: `-0x1A` instead of two's complement.
+//! * Gray code binary, e.g.: for the position of a rotary encoder.
+//!
+//! How do I find how many chars an integer will occupy in decimal, hexadecimal or binary, without formatting it?
+//!
+//! How do I generate fixed width binary or hexadecimal ASCII bytes at compile time, e.g.: for lookup tables?
+//!
+//! How do I right align Debug output, e.g.: `Some(1)` and `None`, in a column like the numeric formatters?
+//!
+//! How do I convert a HashMap or HashSet into a Debug String that is the same on every run?
+//!
+//! How do I convert a value containing floats into a Debug String that does not churn on rounding noise?
+//!
+//! How do I decorate each item with a prefix and suffix, e.g.: `<` and `>`, without a format! closure?
+//!
+//! How do I choose between two formatters for each item, e.g.: hexadecimal only for large values?
+//!
+//! How do I format values in a hot loop without allocating a String for each one?
 //!
 //! ## Use
 //!
 //! * iter.map(binary)
 //! * iter.map(octal)
+//! * iter.map(hex_full)
+//! * iter.map(surround("[", "]"))
+//! * iter.map(fmt_if(|n: &&u32| **n > 255, hex_full, to_string))
+//! * iter.map(debug_pad::<8, _>)
+//! * iter.map(signed_hex)
+//! * iter.map(gray_binary::<4, _>)
+//! * fmt_with_buf(value, |buf, v| write!(buf, "{:x}", v))
 //!
+use crate::align::{pad_to, Alignment};
+use crate::cast::ToI128;
 #[cfg(feature = "itertools")]
 use itertools::Itertools;
-use std::fmt::{Binary, Debug, LowerHex, Octal, UpperHex};
+use std::borrow::Borrow;
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Arguments, Binary, Debug, Display, LowerHex, Octal, UpperHex, Write};
+use std::ops::Deref;
+
+/// A [`std::fmt::Write`] that only counts the bytes written to it
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// The message of the panic when a formatting trait implementation returns an error, as std's ToString panics
+const FMT_ERROR: &str = "a formatting trait implementation returned an error unexpectedly";
+
+/// Format the integer formatting args into a String with a single allocation of exactly the right capacity
+///
+/// **format!** estimates the capacity from the literal text, which is 0 for e.g.: `"{:b}"`,
+/// so the String is reallocated as it grows. The args are formatted twice, once to count the bytes,
+/// which is cheaper than the reallocations for the short output of the integer formatters.
+/// Only use it for integers, Debug and Display implementations may be expensive or have side effects.
+pub(crate) fn format_exact(args: Arguments<'_>) -> String {
+    if let Some(s) = args.as_str() {
+        return s.to_string();
+    }
+    let mut counter = ByteCounter(0);
+    counter.write_fmt(args).expect(FMT_ERROR);
+    format_with_capacity(counter.0, args)
+}
+
+/// Format args into a String allocated with capacity, for output whose length is known in advance,
+/// e.g.: an integer zero padded to the full width of its type
+pub(crate) fn format_with_capacity(capacity: usize, args: Arguments<'_>) -> String {
+    let mut formatted = String::with_capacity(capacity);
+    formatted.write_fmt(args).expect(FMT_ERROR);
+    formatted
+}
 
 /// Convert anything that implements the [`std::fmt::Display`] trait into a String
 ///
@@ -121,6 +191,212 @@ pub fn debug_pretty<D: Debug>(d: D) -> String {
     format!("{:#?}", d)
 }
 
+/// Convert anything that implements the [`std::fmt::Debug`] trait into a String
+/// right aligned in a column of N chars, padded with leading spaces
+///
+/// Use .map(debug_pad::<8, _>)
+///
+/// Unlike **format!("{:8?}",d)** the width applies to the whole Debug output:
+/// derived Debug implementations pass the width on to each field, so `format!("{:8?}", Some(1))`
+/// is `Some(       1)`. Output longer than N is not truncated.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![Some(1), None, Some(100)];
+/// let vec_to_strings: Vec<_> = vec.iter().map(debug_pad::<9, _>).collect();
+/// let expected = vec!["  Some(1)", "     None", "Some(100)"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(format!("{:9?}", Some(1)), "Some(        1)");
+/// ```
+pub fn debug_pad<const N: usize, D: Debug>(d: D) -> String {
+    pad_to(&format!("{:?}", d), N, Alignment::Right)
+}
+
+/// Convert anything that implements the [`std::fmt::Debug`] trait into a String
+/// with each number in it zero padded to N digits
+///
+/// Use .map(debug_zero_pad::<3, _>)
+///
+/// short for **format!("{:03?}",d)**, derived Debug implementations pass the width on to each field,
+/// so the numbers inside an Option, tuple or Vec are padded, and line up when the structure is the same.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![Some(7), Some(42), None];
+/// let vec_to_strings: Vec<_> = vec.iter().map(debug_zero_pad::<3, _>).collect();
+/// let expected = vec!["Some(007)", "Some(042)", "None"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(debug_zero_pad::<2, _>((1, 2, 30)), "(01, 02, 30)");
+/// ```
+pub fn debug_zero_pad<const N: usize, D: Debug>(d: D) -> String {
+    format!("{:0width$?}", d, width = N)
+}
+
+/// Convert a map, such as a [`std::collections::HashMap`], into a Debug String with the keys sorted
+///
+/// The iteration order of a HashMap changes from run to run, so its Debug output does too,
+/// sorting the keys gives stable output for snapshot tests and reproducible logs.
+///
+/// short for **format!("{:?}", map.iter().collect::<BTreeMap<_, _>>())**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::collections::HashMap;
+/// let map = HashMap::from([("c", 3), ("a", 1), ("b", 2)]);
+/// let result = debug_sorted(&map);
+/// let expected = r#"{"a": 1, "b": 2, "c": 3}"#;
+/// assert_eq!(result, expected);
+///
+/// let maps = vec![map.clone(), HashMap::from([("z", 26)])];
+/// let vec_to_strings: Vec<_> = maps.iter().map(debug_sorted).collect();
+/// assert_eq!(vec_to_strings, vec![expected, r#"{"z": 26}"#]);
+/// ```
+pub fn debug_sorted<M, K, V>(map: M) -> String
+where
+    M: IntoIterator<Item = (K, V)>,
+    K: Ord + Debug,
+    V: Debug,
+{
+    format!("{:?}", map.into_iter().collect::<BTreeMap<_, _>>())
+}
+
+/// Convert a set, such as a [`std::collections::HashSet`], into a Debug String with the values sorted
+///
+/// short for **format!("{:?}", set.iter().collect::<BTreeSet<_>>())**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::collections::HashSet;
+/// let set = HashSet::from([3, 1, 2]);
+/// let result = debug_sorted_set(&set);
+/// assert_eq!(result, "{1, 2, 3}");
+/// ```
+pub fn debug_sorted_set<S, T>(set: S) -> String
+where
+    S: IntoIterator<Item = T>,
+    T: Ord + Debug,
+{
+    format!("{:?}", set.into_iter().collect::<BTreeSet<_>>())
+}
+
+/// Rewrite every float literal in Debug output with precision digits after the decimal point,
+/// skipping the contents of quoted Strings and chars, -0 is rounded to 0
+fn normalize_floats(debug: &str, precision: usize) -> String {
+    let mut normalized = String::with_capacity(debug.len());
+    let mut chars = debug.char_indices().peekable();
+    // the quote that opened the String or char literal being copied, so '"' does not open a String
+    let (mut quote, mut escaped) = (None, false);
+    let mut previous = ' ';
+    while let Some((start, c)) = chars.next() {
+        if let Some(open) = quote {
+            normalized.push(c);
+            if !escaped && c == open {
+                quote = None;
+            }
+            escaped = !escaped && c == '\\';
+            continue;
+        }
+        let next_is_digit = chars.peek().is_some_and(|&(_, n)| n.is_ascii_digit());
+        let starts_number = (c.is_ascii_digit() || (c == '-' && next_is_digit))
+            && !(previous.is_alphanumeric() || previous == '_' || previous == '.');
+        if !starts_number {
+            quote = Some(c).filter(|&c| c == '"' || c == '\'');
+            normalized.push(c);
+            previous = c;
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        let mut last = c;
+        while let Some(&(i, n)) = chars.peek() {
+            let exponent_sign = matches!(n, '-' | '+') && matches!(last, 'e' | 'E');
+            if !(n.is_ascii_digit() || matches!(n, '.' | 'e' | 'E') || exponent_sign) {
+                break;
+            }
+            chars.next();
+            end = i + n.len_utf8();
+            last = n;
+        }
+        let token = &debug[start..end];
+        match token.parse::<f64>() {
+            Ok(f) if token.contains(['.', 'e', 'E']) => {
+                let rounded = format!("{:.*}", precision, f);
+                match rounded.strip_prefix('-') {
+                    Some(zero) if zero.chars().all(|d| d == '0' || d == '.') => {
+                        normalized.push_str(zero)
+                    }
+                    _ => normalized.push_str(&rounded),
+                }
+            }
+            _ => normalized.push_str(token),
+        }
+        previous = last;
+    }
+    normalized
+}
+
+/// Convert anything that implements the [`std::fmt::Debug`] trait into a String,
+/// with every float in it rendered with P digits after the decimal point
+///
+/// Use .map(debug_float_normalized::<3, _>)
+///
+/// Floating point noise, e.g.: 0.1 + 0.2 = 0.30000000000000004, makes Debug output churn in snapshot tests,
+/// rounding the floats gives stable output. Integers and the contents of Strings and chars are not changed.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// #[derive(Debug)]
+/// struct Sample {
+///     name: &'static str,
+///     count: u32,
+///     mean: f64,
+///     range: (f32, f32),
+/// }
+///
+/// let sample = Sample { name: "v1.25", count: 3, mean: 0.1 + 0.2, range: (-1e-9, 2.5) };
+/// assert_eq!(format!("{:?}", sample.mean), "0.30000000000000004");
+/// let result = debug_float_normalized::<3, _>(&sample);
+/// let expected = r#"Sample { name: "v1.25", count: 3, mean: 0.300, range: (0.000, 2.500) }"#;
+/// assert_eq!(result, expected);
+///
+/// let vec = vec![Some(1.0 / 3.0), None];
+/// let vec_to_strings: Vec<_> = vec.iter().map(debug_float_normalized::<2, _>).collect();
+/// assert_eq!(vec_to_strings, vec!["Some(0.33)", "None"]);
+///
+/// assert_eq!(debug_float_normalized::<1, _>(('"', 0.1 + 0.2)), r#"('"', 0.3)"#);
+///# assert_eq!(debug_float_normalized::<1, _>(('\'', "1.25", 0.25)), r#"('\'', "1.25", 0.2)"#);
+/// ```
+pub fn debug_float_normalized<const P: usize, D: Debug>(d: D) -> String {
+    normalize_floats(&format!("{:?}", d), P)
+}
+
+/// Convert a collection or iterator of anything that implements the [`std::fmt::Debug`] trait
+/// into a Debug list String, with every float in it rendered with P digits after the decimal point
+///
+/// Unlike [`debug_float_normalized`] the items only need to implement Debug, not the iterator.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let readings = vec![0.1 + 0.2, 1.0, -0.0];
+/// let result = debug_list_float_normalized::<2, _>(readings.iter().map(|r| r * 2.0));
+/// assert_eq!(result, "[0.60, 2.00, 0.00]");
+/// ```
+pub fn debug_list_float_normalized<const P: usize, I>(iter: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Debug,
+{
+    let items: Vec<_> = iter.into_iter().collect();
+    normalize_floats(&format!("{:?}", items), P)
+}
+
 /// Convert numbers that implement the [`std::fmt::Octal`] trait into an octal String
 ///
 /// use .map(octal)
@@ -138,7 +414,7 @@ pub fn debug_pretty<D: Debug>(d: D) -> String {
 ///# assert_eq!(vec_to_strings_consume,stdlib_vec_to_strings);
 /// ```
 pub fn octal<O: Octal>(o: O) -> String {
-    format!("{:o}", o)
+    format_exact(format_args!("{:o}", o))
 }
 
 /// Convert a number that implements the [`std::fmt::Binary`] trait into a binary String
@@ -159,7 +435,7 @@ pub fn octal<O: Octal>(o: O) -> String {
 ///# assert_eq!(vec_to_strings_consume,stdlib_vec_to_strings);
 /// ```
 pub fn binary<B: Binary>(b: B) -> String {
-    format!("{:b}", b)
+    format_exact(format_args!("{:b}", b))
 }
 
 /// Convert a number that implements the [`std::fmt::Binary`] trait into a binary String with padding of leading zeros
@@ -181,7 +457,7 @@ pub fn binary<B: Binary>(b: B) -> String {
 ///# assert_eq!(vec_to_strings_consume,stdlib_vec_to_strings);
 /// ```
 pub fn binary_zero_pad<const N: usize, B: Binary>(b: B) -> String {
-    format!("{:0width$b}", b, width = N)
+    format_exact(format_args!("{:0width$b}", b, width = N))
 }
 
 /// Convert a number that implements the [`std::fmt::Binary`] trait into a binary String with padding of leading spaces
@@ -205,7 +481,7 @@ pub fn binary_zero_pad<const N: usize, B: Binary>(b: B) -> String {
 ///
 /// ```
 pub fn binary_pad<const N: usize, B: Binary>(b: B) -> String {
-    format!("{:width$b}", b, width = N)
+    format_exact(format_args!("{:width$b}", b, width = N))
 }
 
 /// Convert any number that implements the [`std::fmt::LowerHex`] trait into a String
@@ -226,7 +502,7 @@ pub fn binary_pad<const N: usize, B: Binary>(b: B) -> String {
 ///# assert_eq!(vec_to_strings_consume,stdlib_vec_to_strings);
 /// ```
 pub fn lower_hex_pad<const N: usize, H: LowerHex>(h: H) -> String {
-    format!("{:width$x}", h, width = N)
+    format_exact(format_args!("{:width$x}", h, width = N))
 }
 
 /// Convert any number that implements the [`std::fmt::UpperHex`] trait into a String
@@ -247,7 +523,7 @@ pub fn lower_hex_pad<const N: usize, H: LowerHex>(h: H) -> String {
 ///# assert_eq!(vec_to_strings_consume,stdlib_vec_to_strings);
 /// ```
 pub fn upper_hex_pad<const N: usize, H: UpperHex>(h: H) -> String {
-    format!("{:width$X}", h, width = N)
+    format_exact(format_args!("{:width$X}", h, width = N))
 }
 
 /// Convert any number that implements the [`std::fmt::LowerHex`] trait into a String padded with leading zeros
@@ -256,6 +532,8 @@ pub fn upper_hex_pad<const N: usize, H: UpperHex>(h: H) -> String {
 ///
 /// short for **format!("{:02x}",num)**
 ///
+/// For bytes [`hex_byte`] gives the same output faster, using a lookup table.
+///
 /// # Example
 /// ```
 /// use i2u::prelude::*;
@@ -268,7 +546,7 @@ pub fn upper_hex_pad<const N: usize, H: UpperHex>(h: H) -> String {
 /// assert_eq!(vec_to_strings_consume,stdlib_vec_to_strings);
 /// ```
 pub fn lower_hex_zeropad<const N: usize, H: LowerHex>(h: H) -> String {
-    format!("{:0width$x}", h, width = N)
+    format_exact(format_args!("{:0width$x}", h, width = N))
 }
 
 /// Convert any number that implements the [`std::fmt::UpperHex`] trait into a String padded with leading zeros
@@ -289,7 +567,720 @@ pub fn lower_hex_zeropad<const N: usize, H: LowerHex>(h: H) -> String {
 ///# assert_eq!(vec_to_strings_consume,stdlib_vec_to_strings);
 /// ```
 pub fn upper_hex_zeropad<const N: usize, H: UpperHex>(h: H) -> String {
-    format!("{:0width$X}", h, width = N)
+    format_exact(format_args!("{:0width$X}", h, width = N))
+}
+
+/// Integer types with a fixed number of bits, used to pad to the natural width of the type
+///
+/// Implemented for all primitive integers and references to them.
+pub trait BitWidth {
+    /// The number of bits in the type, e.g.: 8 for u8
+    const BITS: usize;
+}
+
+/// Integer types that can be converted into their in memory bytes in an explicit byte order
+///
+/// Implemented for all primitive integers and references to them.
+pub trait IntegerBytes: BitWidth {
+    /// The byte array, e.g.: [u8; 4] for u32
+    type Bytes: AsRef<[u8]>;
+    /// The bytes in big endian (network) order, see [`u32::to_be_bytes`]
+    fn be_bytes(&self) -> Self::Bytes;
+    /// The bytes in little endian order, see [`u32::to_le_bytes`]
+    fn le_bytes(&self) -> Self::Bytes;
+}
+
+macro_rules! impl_integer_traits {
+    ($($t:ty),*) => {
+        $(impl BitWidth for $t {
+            const BITS: usize = <$t>::BITS as usize;
+        }
+
+        impl IntegerBytes for $t {
+            type Bytes = [u8; std::mem::size_of::<$t>()];
+            fn be_bytes(&self) -> Self::Bytes {
+                self.to_be_bytes()
+            }
+            fn le_bytes(&self) -> Self::Bytes {
+                self.to_le_bytes()
+            }
+        })*
+    };
+}
+
+impl_integer_traits!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: BitWidth + ?Sized> BitWidth for &T {
+    const BITS: usize = T::BITS;
+}
+
+impl<T: IntegerBytes + ?Sized> IntegerBytes for &T {
+    type Bytes = T::Bytes;
+    fn be_bytes(&self) -> Self::Bytes {
+        (**self).be_bytes()
+    }
+    fn le_bytes(&self) -> Self::Bytes {
+        (**self).le_bytes()
+    }
+}
+
+/// Integer types split into a sign and magnitude, used to count the chars of their formatted output
+///
+/// Implemented for all primitive integers and references to them.
+pub trait IntegerMagnitude: BitWidth {
+    /// The absolute value, see [`i32::unsigned_abs`], widened to a u128
+    fn magnitude(&self) -> u128;
+    /// True if the integer is less than zero
+    fn is_negative(&self) -> bool;
+}
+
+macro_rules! impl_integer_magnitude {
+    (signed: $($s:ty),*; unsigned: $($u:ty),*) => {
+        $(impl IntegerMagnitude for $s {
+            fn magnitude(&self) -> u128 {
+                self.unsigned_abs() as u128
+            }
+            fn is_negative(&self) -> bool {
+                *self < 0
+            }
+        })*
+        $(impl IntegerMagnitude for $u {
+            fn magnitude(&self) -> u128 {
+                *self as u128
+            }
+            fn is_negative(&self) -> bool {
+                false
+            }
+        })*
+    };
+}
+
+impl_integer_magnitude!(signed: i8, i16, i32, i64, i128, isize; unsigned: u8, u16, u32, u64, u128, usize);
+
+impl<T: IntegerMagnitude + ?Sized> IntegerMagnitude for &T {
+    fn magnitude(&self) -> u128 {
+        (**self).magnitude()
+    }
+    fn is_negative(&self) -> bool {
+        (**self).is_negative()
+    }
+}
+
+/// The number of significant bits in a non negative integer's magnitude, at least 1 so that 0 has a digit
+fn significant_bits<I: IntegerMagnitude>(i: &I) -> usize {
+    (u128::BITS - i.magnitude().leading_zeros()).max(1) as usize
+}
+
+/// The number of chars in the decimal String of an integer, including the minus sign
+///
+/// Use .map(decimal_width)
+///
+/// short for **format!("{}",num).len()** without formatting, e.g.: to compute the width of a column
+/// or the capacity of a buffer before formatting.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![0, 9, 10, -10, 1234567];
+/// let widths: Vec<_> = vec.iter().map(decimal_width).collect();
+/// assert_eq!(widths, vec![1, 1, 2, 3, 7]);
+///
+/// let width = vec.iter().map(decimal_width).max().unwrap_or(0);
+/// let column: Vec<_> = vec.iter().map(|n| format!("{:>width$}", n, width = width)).collect();
+/// assert_eq!(column[0], "      0");
+///# for n in [i128::MIN, i128::MAX, -1, 99, 100, i64::MIN as i128] {
+///#     assert_eq!(decimal_width(n), n.to_string().len());
+///# }
+///# assert_eq!(decimal_width(u128::MAX), u128::MAX.to_string().len());
+/// ```
+pub fn decimal_width<I: IntegerMagnitude>(i: I) -> usize {
+    let digits = match i.magnitude() {
+        0 => 1,
+        magnitude => magnitude.ilog10() as usize + 1,
+    };
+    digits + usize::from(i.is_negative())
+}
+
+/// The number of chars in the hexadecimal String of an integer, without padding or a `0x` prefix
+///
+/// Use .map(hex_width)
+///
+/// short for **format!("{:x}",num).len()** without formatting, negative integers are formatted
+/// in two's complement, so have the full width of their type.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![0_i16, 15, 16, 0x7FFF, -1];
+/// let widths: Vec<_> = vec.iter().map(hex_width).collect();
+/// assert_eq!(widths, vec![1, 1, 2, 4, 4]);
+///# for n in [0_i64, 1, 255, 256, i64::MAX, i64::MIN, -1] {
+///#     assert_eq!(hex_width(n), format!("{:x}", n).len());
+///# }
+/// ```
+pub fn hex_width<I: IntegerMagnitude>(i: I) -> usize {
+    if i.is_negative() {
+        I::BITS / 4
+    } else {
+        significant_bits(&i).div_ceil(4)
+    }
+}
+
+/// The number of chars in the binary String of an integer, as formatted by [`binary`]
+///
+/// Use .map(binary_width)
+///
+/// short for **format!("{:b}",num).len()** without formatting, negative integers are formatted
+/// in two's complement, so have the full width of their type.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![0_i8, 1, 2, 5, 127, -1];
+/// let widths: Vec<_> = vec.iter().map(binary_width).collect();
+/// assert_eq!(widths, vec![1, 1, 2, 3, 7, 8]);
+///# for n in [0_u128, 1, u128::MAX] {
+///#     assert_eq!(binary_width(n), format!("{:b}", n).len());
+///# }
+/// ```
+pub fn binary_width<I: IntegerMagnitude>(i: I) -> usize {
+    if i.is_negative() {
+        I::BITS
+    } else {
+        significant_bits(&i)
+    }
+}
+
+/// Convert an integer into a binary String zero padded to the number of bits in its type
+///
+/// Use .map(binary_full)
+///
+/// short for **format!("{:08b}",num)** for u8, **format!("{:032b}",num)** for u32, etc.
+/// without hardcoding the width
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u8> = vec![0, 5, 255];
+/// let vec_to_strings: Vec<_> = vec.iter().map(binary_full).collect();
+/// let expected = vec!["00000000", "00000101", "11111111"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(binary_full(1_u16), "0000000000000001");
+/// assert_eq!(binary_full(-1_i8), "11111111");
+/// ```
+pub fn binary_full<B: Binary + BitWidth>(b: B) -> String {
+    format_with_capacity(B::BITS, format_args!("{:0width$b}", b, width = B::BITS))
+}
+
+/// Convert an integer into a lower case hexadecimal String zero padded to the number of nibbles in its type
+///
+/// Use .map(hex_full)
+///
+/// short for **format!("{:02x}",num)** for u8, **format!("{:08x}",num)** for u32, etc.
+/// without hardcoding the width
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u32> = vec![0, 0xC0FFEE];
+/// let vec_to_strings: Vec<_> = vec.iter().map(hex_full).collect();
+/// let expected = vec!["00000000", "00c0ffee"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(hex_full(10_u8), "0a");
+/// ```
+pub fn hex_full<H: LowerHex + BitWidth>(h: H) -> String {
+    format_with_capacity(
+        H::BITS / 4,
+        format_args!("{:0width$x}", h, width = H::BITS / 4),
+    )
+}
+
+/// Convert an integer into an upper case hexadecimal String zero padded to the number of nibbles in its type
+///
+/// Use .map(upper_hex_full)
+///
+/// short for **format!("{:04X}",num)** for u16, **format!("{:016X}",num)** for u64, etc.
+/// without hardcoding the width
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u16> = vec![0, 0xBEEF, 10];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(upper_hex_full).collect();
+/// let expected = vec!["0000", "BEEF", "000A"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn upper_hex_full<H: UpperHex + BitWidth>(h: H) -> String {
+    format_with_capacity(
+        H::BITS / 4,
+        format_args!("{:0width$X}", h, width = H::BITS / 4),
+    )
+}
+
+/// Convert an integer into an upper case hexadecimal String of its sign and magnitude, e.g.: `-0x1A`
+///
+/// Use .map(signed_hex)
+///
+/// **format!("{:X}",num)** shows negative numbers in two's complement, e.g.: `FFFFFFE6` for -26_i32,
+/// this shows the value as written in source code.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![-26, 0, 255];
+/// let vec_to_strings: Vec<_> = vec.iter().map(signed_hex).collect();
+/// assert_eq!(vec_to_strings, vec!["-0x1A", "0x0", "0xFF"]);
+///
+/// assert_eq!(format!("{:X}", -26), "FFFFFFE6");
+///# assert_eq!(signed_hex(i64::MIN), "-0x8000000000000000");
+///# assert_eq!(signed_hex(i128::MIN), "-0x80000000000000000000000000000000");
+/// ```
+pub fn signed_hex<I: ToI128>(i: I) -> String {
+    signed_hex_zero_pad::<1, _>(i)
+}
+
+/// Convert an integer into an upper case hexadecimal String of its sign and magnitude,
+/// with the magnitude zero padded to N digits, e.g.: `-0x001A`
+///
+/// Use .map(signed_hex_zero_pad::<4, _>)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![-26, 4096, 0x12345];
+/// let vec_to_strings: Vec<_> = vec.iter().map(signed_hex_zero_pad::<4, _>).collect();
+/// assert_eq!(vec_to_strings, vec!["-0x001A", "0x1000", "0x12345"]);
+/// ```
+pub fn signed_hex_zero_pad<const N: usize, I: ToI128>(i: I) -> String {
+    let i = i.to_i128();
+    let sign = if i < 0 { "-" } else { "" };
+    format_exact(format_args!(
+        "{}0x{:0width$X}",
+        sign,
+        i.unsigned_abs(),
+        width = N
+    ))
+}
+
+/// Convert an integer into a binary String of its sign and magnitude, e.g.: `-11010`
+///
+/// Use .map(signed_binary)
+///
+/// **format!("{:b}",num)** shows negative numbers in two's complement, e.g.: `11100110` for -26_i8,
+/// this shows the value as written in source code.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<i8> = vec![-26, 0, 5];
+/// let vec_to_strings: Vec<_> = vec.iter().map(signed_binary).collect();
+/// assert_eq!(vec_to_strings, vec!["-11010", "0", "101"]);
+///
+/// assert_eq!(format!("{:b}", -26_i8), "11100110");
+///# assert_eq!(signed_binary(i8::MIN), "-10000000");
+/// ```
+pub fn signed_binary<I: ToI128>(i: I) -> String {
+    signed_binary_zero_pad::<1, _>(i)
+}
+
+/// Convert an integer into a binary String of its sign and magnitude,
+/// with the magnitude zero padded to N digits, e.g.: `-00011010`
+///
+/// Use .map(signed_binary_zero_pad::<8, _>)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![-26, 3];
+/// let vec_to_strings: Vec<_> = vec.iter().map(signed_binary_zero_pad::<8, _>).collect();
+/// assert_eq!(vec_to_strings, vec!["-00011010", "00000011"]);
+/// ```
+pub fn signed_binary_zero_pad<const N: usize, I: ToI128>(i: I) -> String {
+    let i = i.to_i128();
+    let sign = if i < 0 { "-" } else { "" };
+    format_exact(format_args!(
+        "{}{:0width$b}",
+        sign,
+        i.unsigned_abs(),
+        width = N
+    ))
+}
+
+/// An unsigned integer that can be converted to and from reflected binary Gray code
+///
+/// Implemented for all unsigned integers and references to them.
+pub trait GrayCode {
+    /// The integer type
+    type Value: Binary;
+    /// The Gray code of the integer, short for **n ^ (n >> 1)**
+    fn gray_encode(&self) -> Self::Value;
+    /// The integer whose Gray code is this value
+    fn gray_decode(&self) -> Self::Value;
+}
+
+macro_rules! impl_gray_code {
+    ($($t:ty),*) => {
+        $(impl GrayCode for $t {
+            type Value = $t;
+            fn gray_encode(&self) -> $t {
+                self ^ (self >> 1)
+            }
+            fn gray_decode(&self) -> $t {
+                let mut n = *self;
+                let mut shift = 1;
+                while shift < <$t>::BITS {
+                    n ^= n >> shift;
+                    shift *= 2;
+                }
+                n
+            }
+        })*
+    };
+}
+
+impl_gray_code!(u8, u16, u32, u64, u128, usize);
+
+impl<T: GrayCode + ?Sized> GrayCode for &T {
+    type Value = T::Value;
+    fn gray_encode(&self) -> T::Value {
+        (**self).gray_encode()
+    }
+    fn gray_decode(&self) -> T::Value {
+        (**self).gray_decode()
+    }
+}
+
+/// Convert an unsigned integer into its reflected binary Gray code, in which consecutive integers
+/// differ in exactly one bit
+///
+/// Use .map(to_gray)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u8> = (0..8).collect();
+/// let gray: Vec<_> = vec.iter().map(to_gray).collect();
+/// assert_eq!(gray, vec![0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]);
+/// ```
+pub fn to_gray<G: GrayCode>(n: G) -> G::Value {
+    n.gray_encode()
+}
+
+/// Convert a reflected binary Gray code back into the unsigned integer it encodes,
+/// e.g.: the position read from a rotary encoder
+///
+/// Use .map(from_gray)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let readings: Vec<u8> = vec![0b000, 0b011, 0b110, 0b100];
+/// let positions: Vec<_> = readings.iter().map(from_gray).collect();
+/// assert_eq!(positions, vec![0, 2, 4, 7]);
+///# assert!((0..=u16::MAX).all(|n| from_gray(to_gray(n)) == n));
+///# assert_eq!(from_gray(to_gray(u128::MAX - 5)), u128::MAX - 5);
+/// ```
+pub fn from_gray<G: GrayCode>(n: G) -> G::Value {
+    n.gray_decode()
+}
+
+/// Convert an unsigned integer into the binary String of its Gray code, zero padded to N digits
+///
+/// Use .map(gray_binary::<4, _>)
+///
+/// short for **format!("{:04b}", to_gray(n))**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u8> = vec![0, 1, 2, 3, 15];
+/// let vec_to_strings: Vec<_> = vec.iter().map(gray_binary::<4, _>).collect();
+/// assert_eq!(vec_to_strings, vec!["0000", "0001", "0011", "0010", "1000"]);
+/// ```
+pub fn gray_binary<const N: usize, G: GrayCode>(n: G) -> String {
+    binary_zero_pad::<N, _>(n.gray_encode())
+}
+
+pub(crate) const LOWER_HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const UPPER_HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Write the N least significant digits of value, each of bits_per_digit bits, most significant first
+const fn digits_array<const N: usize>(
+    value: u64,
+    bits_per_digit: u32,
+    digits: &[u8; 16],
+) -> [u8; N] {
+    let mut array = [0u8; N];
+    let mask = (1u64 << bits_per_digit) - 1;
+    let mut i = 0;
+    while i < N {
+        let shift = (N - 1 - i) as u32 * bits_per_digit;
+        array[i] = digits[((value >> shift) & mask) as usize];
+        i += 1;
+    }
+    array
+}
+
+/// The two lower case hexadecimal digits of every byte, indexed by the byte
+pub(crate) static LOWER_HEX_PAIRS: [[u8; 2]; 256] = {
+    let mut pairs = [[0; 2]; 256];
+    let mut i = 0;
+    while i < 256 {
+        pairs[i] = digits_array::<2>(i as u64, 4, LOWER_HEX_DIGITS);
+        i += 1;
+    }
+    pairs
+};
+
+/// The two upper case hexadecimal digits of every byte, indexed by the byte
+pub(crate) static UPPER_HEX_PAIRS: [[u8; 2]; 256] = {
+    let mut pairs = [[0; 2]; 256];
+    let mut i = 0;
+    while i < 256 {
+        pairs[i] = digits_array::<2>(i as u64, 4, UPPER_HEX_DIGITS);
+        i += 1;
+    }
+    pairs
+};
+
+/// Append the 2 hexadecimal digits of each byte to hex, looked up in pairs, e.g.: [`LOWER_HEX_PAIRS`]
+pub(crate) fn push_hex_pairs(hex: &mut String, bytes: &[u8], pairs: &[[u8; 2]; 256]) {
+    for &byte in bytes {
+        let [high, low] = pairs[usize::from(byte)];
+        hex.push(char::from(high));
+        hex.push(char::from(low));
+    }
+}
+
+/// Convert a byte into a 2 digit lower case hexadecimal String, looking the digits up in a table
+///
+/// Use .map(hex_byte)
+///
+/// The same output as [`lower_hex_zeropad::<2, _>`](lower_hex_zeropad) and **format!("{:02x}",b)**,
+/// but faster as it skips the formatting machinery, see `benches/hex.rs`.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let bytes: Vec<u8> = vec![0, 10, 255];
+/// let vec_to_strings: Vec<_> = bytes.iter().map(hex_byte).collect();
+/// assert_eq!(vec_to_strings, vec!["00", "0a", "ff"]);
+///# let all: Vec<u8> = (0..=255).collect();
+///# assert!(all.iter().all(|b| hex_byte(b) == lower_hex_zeropad::<2, _>(b)));
+/// ```
+pub fn hex_byte<B: Borrow<u8>>(b: B) -> String {
+    let mut hex = String::with_capacity(2);
+    push_hex_pairs(&mut hex, &[*b.borrow()], &LOWER_HEX_PAIRS);
+    hex
+}
+
+macro_rules! const_full_fns {
+    ($($name:ident, $t:ty, $n:literal, $bits:literal, $digits:ident, $like:ident, $input:literal, $expected:literal;)*) => {
+        $(#[doc = concat!(
+            "Convert a ", stringify!($t), " into ASCII bytes zero padded to the full width of the type, the const equivalent of [`",
+            stringify!($like), "`]"
+        )]
+        ///
+        /// Can be evaluated at compile time, e.g.: to generate lookup tables and static Strings.
+        ///
+        /// # Example
+        /// ```
+        /// use i2u::prelude::*;
+        #[doc = concat!("const BYTES: [u8; ", $n, "] = ", stringify!($name), "(", $input, ");")]
+        /// const STR: &str = match std::str::from_utf8(&BYTES) {
+        ///     Ok(s) => s,
+        ///     Err(_) => panic!("not ASCII"),
+        /// };
+        #[doc = concat!("assert_eq!(STR, \"", $expected, "\");")]
+        #[doc = concat!("assert_eq!(STR, ", stringify!($like), "(", $input, "_", stringify!($t), "));")]
+        /// ```
+        pub const fn $name(n: $t) -> [u8; $n] {
+            digits_array::<$n>(n as u64, $bits, $digits)
+        })*
+    };
+}
+
+const_full_fns! {
+    binary_full_u8, u8, 8, 1, LOWER_HEX_DIGITS, binary_full, "0x5A", "01011010";
+    binary_full_u16, u16, 16, 1, LOWER_HEX_DIGITS, binary_full, "0x5A0F", "0101101000001111";
+    binary_full_u32, u32, 32, 1, LOWER_HEX_DIGITS, binary_full, "5", "00000000000000000000000000000101";
+    hex_full_u8, u8, 2, 4, LOWER_HEX_DIGITS, hex_full, "0xA", "0a";
+    hex_full_u16, u16, 4, 4, LOWER_HEX_DIGITS, hex_full, "0xBEEF", "beef";
+    hex_full_u32, u32, 8, 4, LOWER_HEX_DIGITS, hex_full, "0xDEADBEEF", "deadbeef";
+    hex_full_u64, u64, 16, 4, LOWER_HEX_DIGITS, hex_full, "0xC0FFEE", "0000000000c0ffee";
+    upper_hex_full_u8, u8, 2, 4, UPPER_HEX_DIGITS, upper_hex_full, "0xA", "0A";
+    upper_hex_full_u16, u16, 4, 4, UPPER_HEX_DIGITS, upper_hex_full, "0xBEEF", "BEEF";
+    upper_hex_full_u32, u32, 8, 4, UPPER_HEX_DIGITS, upper_hex_full, "0xDEADBEEF", "DEADBEEF";
+    upper_hex_full_u64, u64, 16, 4, UPPER_HEX_DIGITS, upper_hex_full, "0xC0FFEE", "0000000000C0FFEE";
+}
+
+/// Convert an integer into an upper case hexadecimal String of its bytes in big endian (network) order
+///
+/// Use .map(hex_be)
+///
+/// For non negative values this is the same as [`upper_hex_full`], it is the byte order sent on the wire.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u32> = vec![0x0A0B0C0D, 1];
+/// let vec_to_strings: Vec<_> = vec.iter().map(hex_be).collect();
+/// let expected = vec!["0A0B0C0D", "00000001"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(hex_be(-2_i16), "FFFE");
+/// ```
+pub fn hex_be<I: IntegerBytes>(i: I) -> String {
+    let bytes = i.be_bytes();
+    let mut hex = String::with_capacity(bytes.as_ref().len() * 2);
+    push_hex_pairs(&mut hex, bytes.as_ref(), &UPPER_HEX_PAIRS);
+    hex
+}
+
+/// Convert an integer into an upper case hexadecimal String of its bytes in little endian order
+///
+/// Use .map(hex_le)
+///
+/// This is the order the bytes are stored in memory on x86 and most ARM processors,
+/// which logical **format!("{:X}",num)** output hides.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u32> = vec![0x0A0B0C0D, 1];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(hex_le).collect();
+/// let expected = vec!["0D0C0B0A", "01000000"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn hex_le<I: IntegerBytes>(i: I) -> String {
+    let bytes = i.le_bytes();
+    let mut hex = String::with_capacity(bytes.as_ref().len() * 2);
+    push_hex_pairs(&mut hex, bytes.as_ref(), &UPPER_HEX_PAIRS);
+    hex
+}
+
+/// The order of the bytes of an integer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Endian {
+    /// Most significant byte first, also known as network byte order
+    #[default]
+    Big,
+    /// Least significant byte first
+    Little,
+}
+
+/// Convert an integer into upper case hexadecimal byte pairs separated by spaces, in big endian order
+///
+/// Use .map(hex_bytes)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u32> = vec![0xDEADBEEF, 0xC0FFEE];
+/// let vec_to_strings: Vec<_> = vec.iter().map(hex_bytes).collect();
+/// let expected = vec!["DE AD BE EF", "00 C0 FF EE"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn hex_bytes<I: IntegerBytes>(i: I) -> String {
+    hex_bytes_with(i, Endian::Big, " ")
+}
+
+/// Convert an integer into upper case hexadecimal byte pairs in the given byte order joined with separator
+///
+/// Use .map(|i| hex_bytes_with(i, Endian::Little, ":"))
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = hex_bytes_with(0xDEADBEEF_u32, Endian::Little, ":");
+/// assert_eq!(result, "EF:BE:AD:DE");
+///
+/// let result = hex_bytes_with(0x0102_u16, Endian::Big, "");
+/// assert_eq!(result, "0102");
+/// ```
+pub fn hex_bytes_with<I: IntegerBytes, S: AsRef<str>>(
+    i: I,
+    endian: Endian,
+    separator: S,
+) -> String {
+    let bytes = match endian {
+        Endian::Big => i.be_bytes(),
+        Endian::Little => i.le_bytes(),
+    };
+    let (bytes, separator) = (bytes.as_ref(), separator.as_ref());
+    let separators = bytes.len().saturating_sub(1) * separator.len();
+    let mut hex = String::with_capacity(bytes.len() * 2 + separators);
+    for (n, byte) in bytes.iter().enumerate() {
+        if n > 0 {
+            hex.push_str(separator);
+        }
+        push_hex_pairs(&mut hex, &[*byte], &UPPER_HEX_PAIRS);
+    }
+    hex
+}
+
+/// Returns a mapper that formats each item that implements [`std::fmt::Display`] between prefix and suffix
+///
+/// Use .map(surround("<", ">"))
+///
+/// short for **.map(|t| format!("<{}>", t))**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1, 2, 3];
+/// let vec_to_strings: Vec<_> = vec.iter().map(surround("[", "]")).collect();
+/// assert_eq!(vec_to_strings, vec!["[1]", "[2]", "[3]"]);
+///
+/// let tags: Vec<_> = ["b", "i"].into_iter().map(surround('<', '>')).collect();
+/// assert_eq!(tags, vec!["<b>", "<i>"]);
+///
+/// let hex: Vec<_> = vec.into_iter().map(hex_full::<u8>).map(surround("0x", "")).collect();
+/// assert_eq!(hex, vec!["0x01", "0x02", "0x03"]);
+/// ```
+pub fn surround<P: Display, S: Display, T: Display>(prefix: P, suffix: S) -> impl Fn(T) -> String {
+    move |t| format!("{}{}{}", prefix, t, suffix)
+}
+
+/// Returns a mapper that formats each item with then_fmt if pred returns true for it, otherwise with else_fmt
+///
+/// Use .map(fmt_if(pred, then_fmt, else_fmt)), where then_fmt and else_fmt are any of the formatters in this crate,
+/// or closures returning a String.
+///
+/// short for **.map(|t| if pred(&t) { then_fmt(t) } else { else_fmt(t) })**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec: Vec<u32> = vec![7, 255, 4096];
+/// let vec_to_strings: Vec<_> = vec
+///     .iter()
+///     .map(fmt_if(|n: &&u32| **n > 255, hex_full, to_string))
+///     .collect();
+/// assert_eq!(vec_to_strings, vec!["7", "255", "00001000"]);
+///
+/// let options = vec![Some(1), None];
+/// let vec_to_strings: Vec<_> = options
+///     .into_iter()
+///     .map(fmt_if(Option::is_some, debug, |_| String::from("-")))
+///     .collect();
+/// assert_eq!(vec_to_strings, vec!["Some(1)", "-"]);
+/// ```
+pub fn fmt_if<T, P, A, B>(pred: P, then_fmt: A, else_fmt: B) -> impl Fn(T) -> String
+where
+    P: Fn(&T) -> bool,
+    A: Fn(T) -> String,
+    B: Fn(T) -> String,
+{
+    move |t| {
+        if pred(&t) {
+            then_fmt(t)
+        } else {
+            else_fmt(t)
+        }
+    }
 }
 
 #[cfg(feature = "itertools")]
@@ -319,3 +1310,92 @@ pub fn chunk_join<S: AsRef<str>, R: AsRef<str>>(
         .map(|mut chunk| chunk.join(""))
         .join(separator.as_ref())
 }
+
+thread_local! {
+    /// The buffer reused by [`fmt_with_buf`], empty while a [`ScratchBuf`] has borrowed it
+    static SCRATCH: Cell<String> = const { Cell::new(String::new()) };
+}
+
+/// Text formatted into the thread local scratch buffer by [`fmt_with_buf`],
+/// derefs to a &str and gives the buffer back when dropped
+pub struct ScratchBuf {
+    buf: String,
+}
+
+impl Deref for ScratchBuf {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.buf
+    }
+}
+
+impl AsRef<str> for ScratchBuf {
+    fn as_ref(&self) -> &str {
+        &self.buf
+    }
+}
+
+impl Display for ScratchBuf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.buf)
+    }
+}
+
+impl Debug for ScratchBuf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.buf, f)
+    }
+}
+
+impl Drop for ScratchBuf {
+    fn drop(&mut self) {
+        let buf = std::mem::take(&mut self.buf);
+        // keep the larger buffer if a nested call put one back first
+        let _ = SCRATCH.try_with(|scratch| {
+            let previous = scratch.take();
+            scratch.set(if previous.capacity() > buf.capacity() {
+                previous
+            } else {
+                buf
+            });
+        });
+    }
+}
+
+/// Format value with fmt_fn into a thread local buffer that is reused by the next call,
+/// for transient formatting in hot loops where even one allocation per item is too much
+///
+/// fmt_fn writes into a cleared String, e.g.: with `write!`, the returned [`ScratchBuf`] derefs to the &str
+/// and gives the buffer back when dropped, so once the buffer has grown to fit the output no more
+/// allocations are made. If a ScratchBuf is still alive, e.g.: when nesting calls or keeping the results,
+/// a new buffer is used, so the results are never overwritten.
+///
+/// # Panics
+///
+/// If fmt_fn returns an error, like [`ToString::to_string`] when a Display implementation returns an error
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::fmt::Write;
+/// let mut out = Vec::new();
+/// for n in [255_u32, 4096, 65535] {
+///     let hex = fmt_with_buf(n, |buf, n| write!(buf, "{:#06x}", n));
+///     out.extend_from_slice(hex.as_bytes());
+///     out.push(b' ');
+/// }
+/// assert_eq!(out, b"0x00ff 0x1000 0xffff ");
+///
+/// let first = fmt_with_buf(1, |buf, n| write!(buf, "{}", n));
+/// let second = fmt_with_buf(2, |buf, n| write!(buf, "{}", n));
+/// assert_eq!((&*first, &*second), ("1", "2"));
+/// ```
+pub fn fmt_with_buf<T, F>(value: T, fmt_fn: F) -> ScratchBuf
+where
+    F: FnOnce(&mut String, T) -> std::fmt::Result,
+{
+    let mut buf = SCRATCH.try_with(Cell::take).unwrap_or_default();
+    buf.clear();
+    fmt_fn(&mut buf, value).expect("a formatting function returned an error unexpectedly");
+    ScratchBuf { buf }
+}
@@ -0,0 +1,289 @@
+//! Provides adapters that format data as it is written through them
+//!
+//! ## The answer to the question:
+//!
+//! How do I inspect the bytes written to a file or socket without changing the code that writes them?
+//!
+//! How do I nest the output of an existing Display implementation in an indented report?
+//!
+//! How do I break a long base64 or hex blob into fixed width lines, e.g.: for PEM?
+//!
+//! ## Use
+//!
+//! * HexWriter::new(socket, std::io::stderr())
+//! * write!(IndentWriter::new(&mut report, "    "), "{}", value)
+//! * iter.map(|s| indent(s, "  "))
+//! * write!(WrapWriter::new(&mut pem, 64), "{}", base64)
+//!
+use crate::encode::{hexdump_line, HEXDUMP_BYTES_PER_LINE};
+use std::fmt;
+use std::io::{self, Sink, Write};
+
+/// An [`std::io::Write`] adapter that passes bytes through to an inner writer,
+/// while writing an xxd style hex dump of them to a dump writer
+///
+/// Complete lines of 16 bytes are dumped as they are written,
+/// call [`HexWriter::finish`] to dump the final partial line.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::io::Write;
+///
+/// let mut writer = HexWriter::new(Vec::new(), Vec::new());
+/// writer.write_all(b"GET / HTTP/1.1\r\n").unwrap();
+/// writer.write_all(b"\r\n").unwrap();
+/// let (passed_through, dump) = writer.finish().unwrap();
+///
+/// assert_eq!(passed_through, b"GET / HTTP/1.1\r\n\r\n");
+/// let expected = concat!(
+///     "00000000: 4745 5420 2f20 4854 5450 2f31 2e31 0d0a  GET / HTTP/1.1..\n",
+///     "00000010: 0d0a                                     ..\n",
+/// );
+/// assert_eq!(String::from_utf8(dump).unwrap(), expected);
+/// ```
+#[derive(Debug)]
+pub struct HexWriter<W: Write, D: Write> {
+    inner: W,
+    dump: D,
+    offset: usize,
+    line: Vec<u8>,
+}
+
+impl<W: Write, D: Write> HexWriter<W, D> {
+    /// Pass bytes through to inner and write a hex dump of them to dump
+    pub fn new(inner: W, dump: D) -> Self {
+        HexWriter {
+            inner,
+            dump,
+            offset: 0,
+            line: Vec::with_capacity(HEXDUMP_BYTES_PER_LINE),
+        }
+    }
+
+    /// The number of bytes written so far
+    pub fn offset(&self) -> usize {
+        self.offset + self.line.len()
+    }
+
+    /// Dump the final partial line, flush both writers and return them
+    pub fn finish(mut self) -> io::Result<(W, D)> {
+        if !self.line.is_empty() {
+            self.dump_line()?;
+        }
+        self.flush()?;
+        Ok((self.inner, self.dump))
+    }
+
+    fn dump_line(&mut self) -> io::Result<()> {
+        writeln!(self.dump, "{}", hexdump_line(self.offset, &self.line))?;
+        self.offset += self.line.len();
+        self.line.clear();
+        Ok(())
+    }
+}
+
+impl<D: Write> HexWriter<Sink, D> {
+    /// Swallow the bytes and only write a hex dump of them to dump
+    ///
+    /// # Example
+    /// ```
+    /// use i2u::prelude::*;
+    /// use std::io::Write;
+    ///
+    /// let mut writer = HexWriter::dump_only(Vec::new());
+    /// write!(writer, "{}", 42).unwrap();
+    /// let (_, dump) = writer.finish().unwrap();
+    /// assert_eq!(String::from_utf8(dump).unwrap(), format!("00000000: 3432{:37}42\n", ""));
+    /// ```
+    pub fn dump_only(dump: D) -> Self {
+        HexWriter::new(io::sink(), dump)
+    }
+}
+
+impl<W: Write, D: Write> Write for HexWriter<W, D> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        let mut rest = &buf[..written];
+        while !rest.is_empty() {
+            let take = (HEXDUMP_BYTES_PER_LINE - self.line.len()).min(rest.len());
+            self.line.extend_from_slice(&rest[..take]);
+            rest = &rest[take..];
+            if self.line.len() == HEXDUMP_BYTES_PER_LINE {
+                self.dump_line()?;
+            }
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()?;
+        self.dump.flush()
+    }
+}
+
+/// A [`std::fmt::Write`] adapter that prefixes every line written through it, e.g.: with spaces to indent it
+///
+/// Empty lines are not prefixed, so no trailing whitespace is added.
+/// Lines are tracked across writes, so the output of any Display implementation can be nested.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::fmt::Write;
+///
+/// let mut report = String::from("Config:\n");
+/// let nested = "name: i2u\n\nversion: 1";
+/// write!(IndentWriter::new(&mut report, "    "), "{}", nested).unwrap();
+/// assert_eq!(report, "Config:\n    name: i2u\n\n    version: 1");
+/// ```
+#[derive(Debug)]
+pub struct IndentWriter<W: fmt::Write, P: AsRef<str>> {
+    inner: W,
+    prefix: P,
+    at_line_start: bool,
+}
+
+impl<W: fmt::Write, P: AsRef<str>> IndentWriter<W, P> {
+    /// Prefix every line written to inner with prefix
+    pub fn new(inner: W, prefix: P) -> Self {
+        IndentWriter {
+            inner,
+            prefix,
+            at_line_start: true,
+        }
+    }
+
+    /// Return the inner writer
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: fmt::Write, P: AsRef<str>> fmt::Write for IndentWriter<W, P> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for line in s.split_inclusive('\n') {
+            if self.at_line_start && line != "\n" {
+                self.inner.write_str(self.prefix.as_ref())?;
+            }
+            self.inner.write_str(line)?;
+            self.at_line_start = line.ends_with('\n');
+        }
+        Ok(())
+    }
+}
+
+/// Convert a String or &str into a String with every non empty line prefixed, e.g.: with spaces to indent it
+///
+/// Use .map(|s| indent(s, "  "))
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = indent("fn main() {}\n\nfn test() {}\n", "    ");
+/// assert_eq!(result, "    fn main() {}\n\n    fn test() {}\n");
+///
+/// let quoted = indent(debug_pretty(Some(1)), "> ");
+/// assert_eq!(quoted, "> Some(\n>     1,\n> )");
+/// ```
+pub fn indent<S: AsRef<str>, P: AsRef<str>>(s: S, prefix: P) -> String {
+    let mut writer = IndentWriter::new(String::with_capacity(s.as_ref().len()), prefix);
+    fmt::Write::write_str(&mut writer, s.as_ref()).expect("writing to a String cannot fail");
+    writer.into_inner()
+}
+
+/// A [`std::fmt::Write`] and [`std::io::Write`] adapter that inserts a new line after every width chars,
+/// as the data is written through it
+///
+/// New lines already in the data reset the column, no new line is added after the last line.
+/// For [`std::io::Write`] the width is counted in bytes, intended for ASCII data such as base64 or hexadecimal.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::fmt::Write;
+///
+/// let mut pem = String::from("-----BEGIN KEY-----\n");
+/// let mut writer = WrapWriter::new(&mut pem, 8);
+/// write!(writer, "{}", "0123456789ABCDEF").unwrap();
+/// write!(writer, "{}", "GHIJ").unwrap();
+/// pem.push_str("\n-----END KEY-----");
+/// assert_eq!(pem, "-----BEGIN KEY-----\n01234567\n89ABCDEF\nGHIJ\n-----END KEY-----");
+/// ```
+///
+/// ```
+/// use i2u::prelude::*;
+/// use std::io::Write;
+///
+/// let mut writer = WrapWriter::new(Vec::new(), 4);
+/// writer.write_all(b"deadbeefc0").unwrap();
+/// assert_eq!(writer.into_inner(), b"dead\nbeef\nc0");
+/// ```
+#[derive(Debug)]
+pub struct WrapWriter<W> {
+    inner: W,
+    width: usize,
+    column: usize,
+}
+
+impl<W> WrapWriter<W> {
+    /// Insert a new line into the data written to inner after every width chars
+    ///
+    /// # Panics
+    ///
+    /// If width is 0
+    pub fn new(inner: W, width: usize) -> Self {
+        assert!(width > 0, "width must be greater than 0");
+        WrapWriter {
+            inner,
+            width,
+            column: 0,
+        }
+    }
+
+    /// Return the inner writer
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Whether a new line must be inserted before the next char, and update the column for it
+    fn needs_break(&mut self, next_is_new_line: bool) -> bool {
+        if next_is_new_line {
+            self.column = 0;
+            return false;
+        }
+        let needs_break = self.column == self.width;
+        self.column = if needs_break { 1 } else { self.column + 1 };
+        needs_break
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for WrapWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.needs_break(c == '\n') {
+                self.inner.write_char('\n')?;
+            }
+            self.inner.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for WrapWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut wrapped = Vec::with_capacity(buf.len() + buf.len() / self.width + 1);
+        for &byte in buf {
+            if self.needs_break(byte == b'\n') {
+                wrapped.push(b'\n');
+            }
+            wrapped.push(byte);
+        }
+        self.inner.write_all(&wrapped)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
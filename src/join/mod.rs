@@ -0,0 +1,564 @@
+//! Provides functions for joining the items of a collection or iterator into a single String
+//!
+//! ## The answer to the question:
+//!
+//! How do I join a vector or iterator into a String for a message to a person, e.g.: `a, b and c`?
+//!
+//! How do I join a vector or iterator into a list of quoted items, e.g.: `'a', 'b', 'c'` for an SQL IN list?
+//!
+//! How do I convert a vector or iterator into a JSON array, e.g.: `["a","b"]`, without serde?
+//!
+//! How do I repeat a String n times with a separator between, e.g.: `?, ?, ?` for SQL placeholders?
+//!
+//! How do I alternate the items of two iterators, e.g.: labels and measurements, into one String?
+//!
+//! How do I log a huge collection without a megabyte line, e.g.: `1, 2, 3, … (994 more) …, 998, 999, 1000`?
+//!
+//! How do I join a sequence with long runs of the same value compactly, e.g.: `idle ×3, busy, idle ×2`?
+//!
+//! How do I report the successes and failures of an iterator of Results in one call?
+//!
+//! How do I print a slice or map of Display items, e.g.: `[1, 2, 3]`, without a Debug derive or collect and join?
+//!
+//! ## Use
+//!
+//! * join_human(iter, "and")
+//! * join_human_oxford(iter, "or")
+//! * quote_join(iter, ", ")
+//! * json_array(iter)
+//! * json_array_numbers(iter)
+//! * repeat_join("?", n, ", ")
+//! * interleave_join(labels, values, " ")
+//! * join_elided(iter, ", ", 3, 3)
+//! * join_counted(iter, ", ")
+//! * println!("{}", format_partition(results))
+//! * println!("{}", DisplaySlice(&vec))
+//! * println!("{}", DisplayMap(&map))
+//!
+use crate::cast::Primitive;
+use crate::escape::escape_json;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+/// Join items that implement [`std::fmt::Display`] with commas, except the last two which are joined
+/// with the conjunction, optionally also preceded by a comma
+fn join_list<I, C>(iter: I, conjunction: C, oxford_comma: bool) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+    C: Display,
+{
+    let items: Vec<String> = iter.into_iter().map(|item| item.to_string()).collect();
+    match items.as_slice() {
+        [] => String::new(),
+        [only] => only.clone(),
+        [first, second] => format!("{} {} {}", first, conjunction, second),
+        [init @ .., last] => format!(
+            "{}{} {} {}",
+            init.join(", "),
+            if oxford_comma { "," } else { "" },
+            conjunction,
+            last
+        ),
+    }
+}
+
+/// Join items that implement [`std::fmt::Display`] into a natural language list, e.g.: `a, b and c`
+///
+/// All but the last item are separated by commas, the last item is preceded by the conjunction.
+/// An empty collection is an empty String and a single item is returned alone.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let missing = vec!["name", "email", "phone"];
+/// let message = format!("Please enter your {}", join_human(&missing, "and"));
+/// assert_eq!(message, "Please enter your name, email and phone");
+///
+/// assert_eq!(join_human([1, 2], "or"), "1 or 2");
+/// assert_eq!(join_human(["only"], "and"), "only");
+/// assert_eq!(join_human(Vec::<String>::new(), "and"), "");
+/// ```
+pub fn join_human<I, C>(iter: I, conjunction: C) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+    C: Display,
+{
+    join_list(iter, conjunction, false)
+}
+
+/// Join items that implement [`std::fmt::Display`] into a natural language list with an Oxford comma,
+/// e.g.: `a, b, and c`
+///
+/// Like [`join_human`], but when there are three or more items a comma also precedes the conjunction.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let colors = vec!["red", "green", "blue"];
+/// assert_eq!(join_human_oxford(&colors, "or"), "red, green, or blue");
+/// assert_eq!(join_human_oxford(&colors[..2], "or"), "red or green");
+/// ```
+pub fn join_human_oxford<I, C>(iter: I, conjunction: C) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+    C: Display,
+{
+    join_list(iter, conjunction, true)
+}
+
+/// Wrap items that implement [`std::fmt::Display`] in single quotes and join them with separator,
+/// e.g.: `'a', 'b', 'c'`
+///
+/// A single quote inside an item is doubled, as in SQL, so the result can be used for an IN list.
+///
+/// short for **iter.map(|i| format!("'{}'", i)).collect::<Vec<_>>().join(separator)** but with escaping
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let names = vec!["alice", "bob", "o'brien"];
+/// let sql = format!("SELECT * FROM users WHERE name IN ({})", quote_join(&names, ", "));
+/// assert_eq!(sql, "SELECT * FROM users WHERE name IN ('alice', 'bob', 'o''brien')");
+///
+/// assert_eq!(quote_join(1..=3, " "), "'1' '2' '3'");
+/// ```
+pub fn quote_join<I, S>(iter: I, separator: S) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+    S: AsRef<str>,
+{
+    quote_join_with(iter, '\'', separator)
+}
+
+/// Wrap items that implement [`std::fmt::Display`] in quote and join them with separator,
+/// a quote inside an item is doubled
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let unknown = vec!["colour", "say \"hi\""];
+/// let message = format!("unknown fields: {}", quote_join_with(&unknown, '"', ", "));
+/// assert_eq!(message, r#"unknown fields: "colour", "say ""hi""""#);
+///
+/// assert_eq!(quote_join_with(["a", "b"], '`', "|"), "`a`|`b`");
+/// ```
+pub fn quote_join_with<I, S>(iter: I, quote: char, separator: S) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+    S: AsRef<str>,
+{
+    let doubled = String::from_iter([quote, quote]);
+    iter.into_iter()
+        .map(|item| {
+            let item = item.to_string().replace(quote, &doubled);
+            format!("{}{}{}", quote, item, quote)
+        })
+        .collect::<Vec<_>>()
+        .join(separator.as_ref())
+}
+
+/// Convert items that implement [`std::fmt::Display`] into a compact JSON array of strings, e.g.: `["a","b"]`
+///
+/// Each item is escaped with [`escape_json`], so quotes, backslashes and control chars are safe.
+/// `<`, `>` and `&` are not escaped, so the output is not safe to embed in an HTML `<script>` as it is.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["a", "say \"hi\"", "tab\t"];
+/// assert_eq!(json_array(&vec), r#"["a","say \"hi\"","tab\t"]"#);
+///
+/// assert_eq!(json_array(1..=3), r#"["1","2","3"]"#);
+/// assert_eq!(json_array(Vec::<String>::new()), "[]");
+/// ```
+pub fn json_array<I>(iter: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    let items: Vec<String> = iter
+        .into_iter()
+        .map(|item| format!("\"{}\"", escape_json(item.to_string())))
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+/// Convert integers or floats into a compact JSON array of numbers, e.g.: `[1,2.5,3]`
+///
+/// NaN and infinity cannot be represented in JSON, they are written as `null`.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1.0, 2.5, -0.125];
+/// assert_eq!(json_array_numbers(&vec), "[1,2.5,-0.125]");
+///
+/// assert_eq!(json_array_numbers([f64::NAN, 1e300]), format!("[null,{}]", 1e300));
+/// assert_eq!(json_array_numbers(vec![u64::MAX]), "[18446744073709551615]");
+/// ```
+pub fn json_array_numbers<I>(iter: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Primitive + Display,
+{
+    let items: Vec<String> = iter
+        .into_iter()
+        .map(|n| {
+            if n.to_f64_lossy().is_finite() {
+                n.to_string()
+            } else {
+                String::from("null")
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+/// Repeat s n times joined with separator, without a trailing separator, e.g.: `?, ?, ?`
+///
+/// short for **vec![s; n].join(separator)** without allocating the Vec
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let columns = vec!["id", "name", "email"];
+/// let sql = format!(
+///     "INSERT INTO users ({}) VALUES ({})",
+///     columns.join(", "),
+///     repeat_join("?", columns.len(), ", ")
+/// );
+/// assert_eq!(sql, "INSERT INTO users (id, name, email) VALUES (?, ?, ?)");
+///
+/// assert_eq!(repeat_join("ab", 0, "-"), "");
+/// assert_eq!(repeat_join("ab", 1, "-"), "ab");
+/// ```
+pub fn repeat_join<S: AsRef<str>, P: AsRef<str>>(s: S, n: usize, separator: P) -> String {
+    let (s, separator) = (s.as_ref(), separator.as_ref());
+    let mut joined = String::with_capacity(n * (s.len() + separator.len()));
+    for i in 0..n {
+        if i > 0 {
+            joined.push_str(separator);
+        }
+        joined.push_str(s);
+    }
+    joined
+}
+
+/// Alternate the items of a and b, starting with a, and join them with separator
+///
+/// When one iterator is longer than the other, its remaining items follow in order,
+/// so no item is lost, use `.take()` on the longer one to drop them instead.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let labels = vec!["min", "max"];
+/// let values = vec![1.5, 9.25];
+/// assert_eq!(interleave_join(&labels, &values, " "), "min 1.5 max 9.25");
+///
+/// assert_eq!(interleave_join([1, 2, 3, 4], ["a"], ","), "1,a,2,3,4");
+/// assert_eq!(interleave_join(Vec::<u8>::new(), ["a", "b"], ","), "a,b");
+/// ```
+pub fn interleave_join<A, B, S>(a: A, b: B, separator: S) -> String
+where
+    A: IntoIterator,
+    A::Item: Display,
+    B: IntoIterator,
+    B::Item: Display,
+    S: AsRef<str>,
+{
+    let (mut a, mut b) = (a.into_iter(), b.into_iter());
+    let mut items = Vec::new();
+    loop {
+        let (next_a, next_b) = (a.next(), b.next());
+        if next_a.is_none() && next_b.is_none() {
+            break;
+        }
+        items.extend(next_a.map(|item| item.to_string()));
+        items.extend(next_b.map(|item| item.to_string()));
+    }
+    items.join(separator.as_ref())
+}
+
+/// Join the first head and last tail items with separator, with `… (N more) …` in place of the N items
+/// between them, or all the items if there are no more than head + tail
+///
+/// The items between are counted but not formatted, and only tail items are kept at a time,
+/// so a collection or iterator of any size can be logged with a short, informative line.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = join_elided(1..=1000, ", ", 3, 2);
+/// assert_eq!(result, "1, 2, 3, … (995 more) …, 999, 1000");
+///
+/// assert_eq!(join_elided(["a", "b", "c"], ", ", 2, 1), "a, b, c");
+/// assert_eq!(join_elided(0..10, " ", 0, 1), "… (9 more) … 9");
+///# assert_eq!(join_elided(0..10, " ", 2, 0), "0 1 … (8 more) …");
+///# assert_eq!(join_elided(0..0, " ", 2, 2), "");
+///# assert_eq!(join_elided(0..5, ",", 2, 2), "0,1,… (1 more) …,3,4");
+/// ```
+pub fn join_elided<I, S>(iter: I, separator: S, head: usize, tail: usize) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+    S: AsRef<str>,
+{
+    let mut iter = iter.into_iter();
+    let mut items: Vec<String> = iter
+        .by_ref()
+        .take(head)
+        .map(|item| item.to_string())
+        .collect();
+    let mut last = VecDeque::with_capacity(tail + 1);
+    let mut elided = 0_usize;
+    for item in iter {
+        last.push_back(item);
+        if last.len() > tail {
+            last.pop_front();
+            elided += 1;
+        }
+    }
+    if elided > 0 {
+        items.push(format!("… ({} more) …", elided));
+    }
+    items.extend(last.into_iter().map(|item| item.to_string()));
+    items.join(separator.as_ref())
+}
+
+/// Join items with separator, with each run of consecutive equal items collapsed into one
+/// followed by ` ×` and the length of the run, e.g.: `idle ×3`
+///
+/// Only consecutive items are collapsed, so the order of the sequence is kept,
+/// a run of one item is shown without a count.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let states = vec!["idle", "idle", "idle", "busy", "idle", "idle"];
+/// assert_eq!(join_counted(&states, ", "), "idle ×3, busy, idle ×2");
+///
+/// assert_eq!(join_counted([0, 0, 0, 0, 1, 0], " "), "0 ×4 1 0");
+///# assert_eq!(join_counted(Vec::<u8>::new(), " "), "");
+///# assert_eq!(join_counted([7], " "), "7");
+/// ```
+pub fn join_counted<I, S>(iter: I, separator: S) -> String
+where
+    I: IntoIterator,
+    I::Item: Display + PartialEq,
+    S: AsRef<str>,
+{
+    let mut runs: Vec<(I::Item, usize)> = Vec::new();
+    for item in iter {
+        match runs.last_mut() {
+            Some((last, count)) if *last == item => *count += 1,
+            _ => runs.push((item, 1)),
+        }
+    }
+    runs.into_iter()
+        .map(|(item, count)| match count {
+            1 => item.to_string(),
+            count => format!("{} ×{}", item, count),
+        })
+        .collect::<Vec<_>>()
+        .join(separator.as_ref())
+}
+
+/// The formatted Ok values and error summary of an iterator of Results, returned by [`format_partition`]
+///
+/// Its Display shows both, e.g.: `2 ok: 1, 2; 1 failed: empty`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct FormattedPartition {
+    /// The Ok values joined with `, `
+    pub ok: String,
+    /// The number of Ok values
+    pub ok_count: usize,
+    /// The distinct errors in the order they first occurred joined with `, `,
+    /// an error that occurred more than once is followed by its count, e.g.: `timeout (x3)`
+    pub errors: String,
+    /// The number of errors, including repeats
+    pub error_count: usize,
+}
+
+impl Display for FormattedPartition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ok", self.ok_count)?;
+        if self.ok_count > 0 {
+            write!(f, ": {}", self.ok)?;
+        }
+        write!(f, "; {} failed", self.error_count)?;
+        if self.error_count > 0 {
+            write!(f, ": {}", self.errors)?;
+        }
+        Ok(())
+    }
+}
+
+/// Consume an iterator of Results, where the values and errors implement [`std::fmt::Display`],
+/// into the joined Ok values and a summary of the errors with their counts
+///
+/// Useful for reporting a pipeline where some items fail, without a second pass or manual bookkeeping.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let inputs = vec!["1", "x", "2", "", "y"];
+/// let partition = format_partition(inputs.iter().map(|s| s.parse::<u8>()));
+/// assert_eq!(partition.ok, "1, 2");
+/// assert_eq!(partition.ok_count, 2);
+/// assert_eq!(
+///     partition.errors,
+///     "invalid digit found in string (x2), cannot parse integer from empty string"
+/// );
+/// assert_eq!(partition.error_count, 3);
+///
+/// let results: Vec<Result<u8, String>> = vec![Ok(1), Err("timeout".into())];
+/// assert_eq!(format_partition(results).to_string(), "1 ok: 1; 1 failed: timeout");
+/// ```
+pub fn format_partition<I, T, E>(iter: I) -> FormattedPartition
+where
+    I: IntoIterator<Item = Result<T, E>>,
+    T: Display,
+    E: Display,
+{
+    let mut ok = Vec::new();
+    let mut errors: Vec<(String, usize)> = Vec::new();
+    let mut error_count = 0;
+    for result in iter {
+        match result {
+            Ok(t) => ok.push(t.to_string()),
+            Err(e) => {
+                error_count += 1;
+                let message = e.to_string();
+                match errors.iter_mut().find(|(m, _)| *m == message) {
+                    Some((_, count)) => *count += 1,
+                    None => errors.push((message, 1)),
+                }
+            }
+        }
+    }
+    let errors: Vec<String> = errors
+        .into_iter()
+        .map(|(message, count)| match count {
+            1 => message,
+            _ => format!("{} (x{})", message, count),
+        })
+        .collect();
+    FormattedPartition {
+        ok_count: ok.len(),
+        ok: ok.join(", "),
+        errors: errors.join(", "),
+        error_count,
+    }
+}
+
+/// A wrapper that displays a slice of items that implement [`std::fmt::Display`] in brackets separated by `, `,
+/// like Debug does, but with the Display of each item, e.g.: `[a, b]` rather than `["a", "b"]`
+///
+/// The format options are passed on to each item, so **format!("{:.1}", DisplaySlice(&floats))**
+/// shows every float with 1 decimal place. A Vec or array can be passed with `&vec`.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let names = vec!["alice", "bob"];
+/// assert_eq!(DisplaySlice(&names).to_string(), "[alice, bob]");
+/// assert_eq!(format!("{:?}", names), r#"["alice", "bob"]"#);
+///
+/// let readings = [1.0, 2.25, 3.5];
+/// assert_eq!(format!("{:.1}", DisplaySlice(&readings)), "[1.0, 2.2, 3.5]");
+/// assert_eq!(format!("{:>3}", DisplaySlice(&[1, 22])), "[  1,  22]");
+///
+/// let empty: [u8; 0] = [];
+/// assert_eq!(DisplaySlice(&empty).to_string(), "[]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplaySlice<'a, T>(pub &'a [T]);
+
+impl<T: Display> Display for DisplaySlice<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[")?;
+        for (index, item) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            item.fmt(f)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// A wrapper that displays a map, or any collection of key value pairs that implement [`std::fmt::Display`],
+/// in braces as `key: value` separated by `, `, e.g.: `{a: 1, b: 2}`
+///
+/// The pairs are shown in the iteration order of the map, use a BTreeMap for a stable order.
+/// The format options are passed on to each key and value, like [`DisplaySlice`].
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::collections::BTreeMap;
+/// let mut stock = BTreeMap::new();
+/// stock.insert("apples", 3);
+/// stock.insert("pears", 12);
+/// assert_eq!(DisplayMap(&stock).to_string(), "{apples: 3, pears: 12}");
+///
+/// let pairs = vec![("x", 0.5), ("y", 1.25)];
+/// assert_eq!(format!("{:.2}", DisplayMap(&pairs)), "{x: 0.50, y: 1.25}");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplayMap<'a, M: ?Sized>(pub &'a M);
+
+impl<'a, M> Display for DisplayMap<'a, M>
+where
+    M: ?Sized,
+    &'a M: IntoIterator,
+    <&'a M as IntoIterator>::Item: PairRef,
+    <<&'a M as IntoIterator>::Item as PairRef>::Key: Display,
+    <<&'a M as IntoIterator>::Item as PairRef>::Value: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("{")?;
+        for (index, pair) in self.0.into_iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            let (key, value) = pair.pair_ref();
+            key.fmt(f)?;
+            f.write_str(": ")?;
+            value.fmt(f)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// A key value pair yielded by iterating over a reference to a map, `(&K, &V)`, or a slice of pairs, `&(K, V)`
+pub trait PairRef {
+    /// The type of the key
+    type Key;
+    /// The type of the value
+    type Value;
+    /// References to the key and value
+    fn pair_ref(&self) -> (&Self::Key, &Self::Value);
+}
+
+impl<K, V> PairRef for (&K, &V) {
+    type Key = K;
+    type Value = V;
+    fn pair_ref(&self) -> (&K, &V) {
+        (self.0, self.1)
+    }
+}
+
+impl<K, V> PairRef for &(K, V) {
+    type Key = K;
+    type Value = V;
+    fn pair_ref(&self) -> (&K, &V) {
+        (&self.0, &self.1)
+    }
+}
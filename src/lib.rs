@@ -5,5 +5,33 @@ feature = "document-features",
 cfg_attr(doc, doc = ::document_features::document_features!())
 )]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+pub mod align;
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+pub mod array;
+pub mod cast;
+pub mod color;
+pub mod diff;
+pub mod encode;
+pub mod error;
+pub mod escape;
+pub mod ext;
+pub mod float;
 pub mod fmt;
+pub mod io;
+pub mod join;
+pub mod list;
+pub mod net;
+pub mod num;
+pub mod os;
 pub mod prelude;
+pub mod pretty;
+pub mod secret;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serialize;
+pub mod template;
+pub mod term;
+pub mod text;
+pub mod unicode;
+pub mod units;
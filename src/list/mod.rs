@@ -0,0 +1,254 @@
+//! Provides functions for formatting a collection or iterator as a list for people to read
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator into a numbered list, e.g.: `1. foo` with the numbers aligned?
+//!
+//! How do I convert a vector or iterator into a bulleted list, e.g.: `- foo` or `• foo`?
+//!
+//! How do I abbreviate commit hashes or digests to the shortest prefix that is still unambiguous, like `git log --oneline`?
+//!
+//! How do I display a directory listing or syntax tree, flattened into (depth, label) pairs, as a tree like `tree`?
+//!
+//! ## Use
+//!
+//! * numbered_list(iter)
+//! * numbered_list_with(iter, 0, |i| format!("[{}]", i))
+//! * bullet_list(iter, '-')
+//! * tree(iter_of_depth_and_label)
+//! * abbreviate_unique(iter_of_ids, 7)
+//!
+use crate::align::{display_width, pad_to, Alignment};
+use std::fmt::Display;
+
+/// Join items with a new line, each preceded by its marker,
+/// the lines after the first of a multi line item are indented to line up with its text
+fn render_list<M, T>(items: impl IntoIterator<Item = (M, T)>) -> String
+where
+    M: AsRef<str>,
+    T: Display,
+{
+    items
+        .into_iter()
+        .map(|(marker, item)| {
+            let marker = marker.as_ref();
+            let indent = " ".repeat(display_width(marker));
+            item.to_string()
+                .lines()
+                .enumerate()
+                .map(|(index, line)| match (index, line.is_empty()) {
+                    (0, _) => format!("{}{}", marker, line),
+                    (_, true) => String::new(),
+                    _ => format!("{}{}", indent, line),
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert items that implement [`std::fmt::Display`] into a numbered list, one item per line, e.g.: `1. foo`
+///
+/// The numbers are right aligned so the items line up when there are more than 9,
+/// the lines after the first of a multi line item are indented to line up with its first line.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let todo = vec!["write docs", "release"];
+/// assert_eq!(numbered_list(&todo), "1. write docs\n2. release");
+///
+/// let long = numbered_list((1..=10).map(|i| i * i));
+/// assert!(long.starts_with(" 1. 1\n 2. 4\n"));
+/// assert!(long.ends_with("\n10. 100"));
+///
+/// let multi_line = numbered_list(["first\nmore detail", "second"]);
+/// assert_eq!(multi_line, "1. first\n   more detail\n2. second");
+/// ```
+pub fn numbered_list<I>(iter: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    numbered_list_with(iter, 1, |i| format!("{}.", i))
+}
+
+/// Convert items that implement [`std::fmt::Display`] into a numbered list, one item per line,
+/// numbered from start with each number formatted by index_fmt
+///
+/// The formatted numbers are right aligned and followed by a space.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let steps = vec!["clone", "build", "test"];
+/// let result = numbered_list_with(&steps, 0, |i| format!("[{}]", i));
+/// assert_eq!(result, "[0] clone\n[1] build\n[2] test");
+///
+/// let result = numbered_list_with(&steps, 8, |i| format!("{})", i));
+/// assert_eq!(result, " 8) clone\n 9) build\n10) test");
+/// ```
+pub fn numbered_list_with<I, F>(iter: I, start: usize, index_fmt: F) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+    F: Fn(usize) -> String,
+{
+    let items: Vec<_> = iter.into_iter().collect();
+    let indices: Vec<String> = (start..start + items.len()).map(index_fmt).collect();
+    let width = indices.iter().map(|i| display_width(i)).max().unwrap_or(0);
+    render_list(
+        indices
+            .iter()
+            .map(|index| pad_to(index, width, Alignment::Right) + " ")
+            .zip(items),
+    )
+}
+
+/// Convert items that implement [`std::fmt::Display`] into a bulleted list, one item per line,
+/// each preceded by bullet and a space, e.g.: `- foo`
+///
+/// Use `'-'` for Markdown or `'•'` for plain text, the lines after the first of a multi line item
+/// are indented to line up with its first line, so nested lists can be built from inner lists.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["foo", "bar"];
+/// assert_eq!(bullet_list(&vec, '-'), "- foo\n- bar");
+/// assert_eq!(bullet_list(&vec, '•'), "• foo\n• bar");
+///
+/// let fruit = format!("fruit\n{}", bullet_list(["apple", "pear"], '*'));
+/// let nested = bullet_list([fruit.as_str(), "bread"], '-');
+/// assert_eq!(nested, "- fruit\n  * apple\n  * pear\n- bread");
+/// ```
+pub fn bullet_list<I, B>(iter: I, bullet: B) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+    B: Display,
+{
+    let marker = format!("{} ", bullet);
+    render_list(iter.into_iter().map(|item| (&marker, item)))
+}
+
+/// Convert (depth, item) pairs, where the item implements [`std::fmt::Display`], into a tree drawn
+/// with box drawing chars, like the output of the `tree` command
+///
+/// The pairs are in depth first order, as produced by walking a directory or syntax tree,
+/// depth 0 items are roots and are shown without a connector,
+/// each item is a child of the closest preceding item with a smaller depth.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let walk = vec![
+///     (0, "."),
+///     (1, "Cargo.toml"),
+///     (1, "src"),
+///     (2, "lib.rs"),
+///     (2, "fmt"),
+///     (3, "mod.rs"),
+///     (1, "README.md"),
+/// ];
+/// let expected = concat!(
+///     ".\n",
+///     "├── Cargo.toml\n",
+///     "├── src\n",
+///     "│   ├── lib.rs\n",
+///     "│   └── fmt\n",
+///     "│       └── mod.rs\n",
+///     "└── README.md",
+/// );
+/// assert_eq!(tree(walk), expected);
+/// ```
+pub fn tree<I, T>(iter: I) -> String
+where
+    I: IntoIterator<Item = (usize, T)>,
+    T: Display,
+{
+    let nodes: Vec<(usize, T)> = iter.into_iter().collect();
+    let mut has_later_sibling = Vec::new();
+    let mut is_last = vec![false; nodes.len()];
+    for (index, &(depth, _)) in nodes.iter().enumerate().rev() {
+        has_later_sibling.resize(depth + 1, false);
+        is_last[index] = !has_later_sibling[depth];
+        has_later_sibling[depth] = true;
+    }
+    let mut ancestors_last: Vec<bool> = Vec::new();
+    let mut lines = Vec::with_capacity(nodes.len());
+    for ((depth, item), last) in nodes.into_iter().zip(is_last) {
+        ancestors_last.resize(depth, true);
+        let mut indent: String = ancestors_last
+            .iter()
+            .skip(1)
+            .map(|&ancestor_last| if ancestor_last { "    " } else { "│   " })
+            .collect();
+        let connector = match (depth, last) {
+            (0, _) => "",
+            (_, true) => "└── ",
+            (_, false) => "├── ",
+        };
+        let label = item.to_string();
+        let mut label_lines = label.lines();
+        let first = label_lines.next().unwrap_or_default();
+        lines.push(format!("{}{}{}", indent, connector, first));
+        if depth > 0 {
+            indent.push_str(if last { "    " } else { "│   " });
+        }
+        for line in label_lines {
+            lines.push(format!("{}{}", indent, line).trim_end().to_string());
+        }
+        ancestors_last.push(last);
+    }
+    lines.join("\n")
+}
+
+/// Abbreviate IDs, e.g.: commit hashes or digests, to their first N chars, where N is the shortest length
+/// of at least min_len that leaves every distinct ID with a distinct prefix
+///
+/// All the IDs are abbreviated to the same length, so they line up in a listing,
+/// and the order of the IDs is kept. Repeated IDs are abbreviated the same and do not make the prefix longer,
+/// an ID shorter than N is kept whole.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let commits = vec![
+///     "3f2a9c1d8e7b6a5f4e3d2c1b0a9f8e7d6c5b4a39",
+///     "3f2a9c4b1e0d9c8b7a6f5e4d3c2b1a0f9e8d7c6b",
+///     "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678",
+/// ];
+/// assert_eq!(abbreviate_unique(&commits, 4), vec!["3f2a9c1", "3f2a9c4", "a1b2c3d"]);
+/// assert_eq!(abbreviate_unique(&commits, 10), vec!["3f2a9c1d8e", "3f2a9c4b1e", "a1b2c3d4e5"]);
+///
+/// assert_eq!(abbreviate_unique(["abc", "abcdef", "abc"], 1), vec!["abc", "abcd", "abc"]);
+///# assert_eq!(abbreviate_unique(Vec::<String>::new(), 7), Vec::<String>::new());
+///# assert_eq!(abbreviate_unique(["ééa", "ééb"], 1), vec!["ééa", "ééb"]);
+/// ```
+pub fn abbreviate_unique<I>(iter: I, min_len: usize) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let ids: Vec<I::Item> = iter.into_iter().collect();
+    let mut sorted: Vec<&str> = ids.iter().map(AsRef::as_ref).collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+    // after sorting, the longest common prefix of any two IDs is found between neighbours
+    let len = sorted
+        .windows(2)
+        .map(|pair| {
+            let common = pair[0]
+                .chars()
+                .zip(pair[1].chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            common + 1
+        })
+        .fold(min_len, usize::max);
+    ids.iter()
+        .map(|id| id.as_ref().chars().take(len).collect())
+        .collect()
+}
@@ -0,0 +1,254 @@
+//! Provides functions for formatting network addresses
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator of IP addresses into a String of:
+//!
+//! * an IPv4 address in binary or hexadecimal, e.g.: `11000000.10101000.00000001.00000001` or `c0a80101`.
+//! * an IPv6 address with every group in full, e.g.: `2001:0db8:0000:0000:0000:0000:0000:0001`.
+//! * an IPv6 address in the compressed form of RFC 5952, e.g.: `2001:db8::1`.
+//!
+//! How do I convert a CIDR prefix length into a subnet mask, e.g.: `/24` into `255.255.255.0`?
+//!
+//! How do I see which bits of an address are the network and which are the host, to check a prefix length?
+//!
+//! ## Use
+//!
+//! * iter.map(ipv4_binary)
+//! * iter.map(ipv4_hex)
+//! * iter.map(ipv6_expanded)
+//! * iter.map(ipv6_compressed)
+//! * iter.map(mask_from_prefix)
+//! * cidr_visualize(ip, 26)
+//!
+use std::borrow::Borrow;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// An IPv4 or IPv6 address
+///
+/// Implemented for [`IpAddr`], [`Ipv4Addr`], [`Ipv6Addr`] and references to them.
+pub trait ToIpAddr {
+    /// The address as an IpAddr
+    fn to_ip_addr(&self) -> IpAddr;
+}
+
+impl ToIpAddr for IpAddr {
+    fn to_ip_addr(&self) -> IpAddr {
+        *self
+    }
+}
+
+impl ToIpAddr for Ipv4Addr {
+    fn to_ip_addr(&self) -> IpAddr {
+        IpAddr::V4(*self)
+    }
+}
+
+impl ToIpAddr for Ipv6Addr {
+    fn to_ip_addr(&self) -> IpAddr {
+        IpAddr::V6(*self)
+    }
+}
+
+impl<T: ToIpAddr + ?Sized> ToIpAddr for &T {
+    fn to_ip_addr(&self) -> IpAddr {
+        (**self).to_ip_addr()
+    }
+}
+
+/// The address as IPv4, if it is IPv4 or an IPv4-mapped IPv6 address, e.g.: `::ffff:192.168.1.1`
+fn to_ipv4(ip: IpAddr) -> Option<Ipv4Addr> {
+    match ip {
+        IpAddr::V4(ip) => Some(ip),
+        IpAddr::V6(ip) => ip.to_ipv4_mapped(),
+    }
+}
+
+/// The address as IPv6, with an IPv4 address converted into an IPv4-mapped IPv6 address
+fn to_ipv6(ip: IpAddr) -> Ipv6Addr {
+    match ip {
+        IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+        IpAddr::V6(ip) => ip,
+    }
+}
+
+/// Convert an IPv4 address into a String of its four octets in binary separated by dots
+///
+/// Use .map(ipv4_binary)
+///
+/// An IPv4-mapped IPv6 address is shown as its IPv4 address, any other IPv6 address is shown
+/// as [`ipv6_compressed`], as it has no IPv4 representation.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::net::{IpAddr, Ipv4Addr};
+/// let vec: Vec<IpAddr> = vec!["192.168.1.1".parse().unwrap(), "::ffff:10.0.0.255".parse().unwrap()];
+/// let vec_to_strings: Vec<_> = vec.iter().map(ipv4_binary).collect();
+/// let expected = vec!["11000000.10101000.00000001.00000001", "00001010.00000000.00000000.11111111"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(ipv4_binary(Ipv4Addr::BROADCAST), "11111111.11111111.11111111.11111111");
+/// ```
+pub fn ipv4_binary<A: ToIpAddr>(ip: A) -> String {
+    let ip = ip.to_ip_addr();
+    match to_ipv4(ip) {
+        Some(ip) => ip
+            .octets()
+            .iter()
+            .map(|octet| format!("{:08b}", octet))
+            .collect::<Vec<_>>()
+            .join("."),
+        None => ip.to_string(),
+    }
+}
+
+/// Convert an IPv4 address into a lower case hexadecimal String of its 32 bits, e.g.: `c0a80101`,
+/// as it appears in packet dumps and `/proc/net/tcp`
+///
+/// Use .map(ipv4_hex)
+///
+/// short for **format!("{:08x}", u32::from(ip))**,
+/// an IPv4-mapped IPv6 address is shown as its IPv4 address, any other IPv6 address is shown
+/// as [`ipv6_compressed`], as it has no IPv4 representation.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::net::Ipv4Addr;
+/// let vec = vec![Ipv4Addr::new(192, 168, 1, 1), Ipv4Addr::LOCALHOST];
+/// let vec_to_strings: Vec<_> = vec.iter().map(ipv4_hex).collect();
+/// assert_eq!(vec_to_strings, vec!["c0a80101", "7f000001"]);
+///
+/// let ip: std::net::IpAddr = "2001:db8::1".parse().unwrap();
+/// assert_eq!(ipv4_hex(ip), "2001:db8::1");
+/// ```
+pub fn ipv4_hex<A: ToIpAddr>(ip: A) -> String {
+    let ip = ip.to_ip_addr();
+    match to_ipv4(ip) {
+        Some(ip) => format!("{:08x}", u32::from(ip)),
+        None => ip.to_string(),
+    }
+}
+
+/// Convert an IPv6 address into a String of its eight groups of four lower case hexadecimal digits,
+/// with leading zeros and without `::` compression
+///
+/// Use .map(ipv6_expanded)
+///
+/// The fixed width form sorts and aligns as text and matches the output of tools like `tcpdump -x`,
+/// an IPv4 address is shown as its IPv4-mapped IPv6 address, e.g.: `0000:...:ffff:c0a8:0101`.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::net::{Ipv4Addr, Ipv6Addr};
+/// let vec = vec![Ipv6Addr::LOCALHOST, "2001:db8::8a2e:370:7334".parse().unwrap()];
+/// let vec_to_strings: Vec<_> = vec.iter().map(ipv6_expanded).collect();
+/// let expected = vec![
+///     "0000:0000:0000:0000:0000:0000:0000:0001",
+///     "2001:0db8:0000:0000:0000:8a2e:0370:7334",
+/// ];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// let result = ipv6_expanded(Ipv4Addr::new(192, 168, 1, 1));
+/// assert_eq!(result, "0000:0000:0000:0000:0000:ffff:c0a8:0101");
+/// ```
+pub fn ipv6_expanded<A: ToIpAddr>(ip: A) -> String {
+    to_ipv6(ip.to_ip_addr())
+        .segments()
+        .iter()
+        .map(|segment| format!("{:04x}", segment))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Convert an IPv6 address into its canonical compressed String, as recommended by RFC 5952
+///
+/// Use .map(ipv6_compressed)
+///
+/// Leading zeros are removed and the longest run of zero groups is replaced by `::`,
+/// the same as **format!("{}", ip)** for an Ipv6Addr, but an IPv4 address is shown as its IPv4-mapped
+/// IPv6 address, e.g.: `::ffff:192.168.1.1`, so a mixed iterator produces IPv6 only.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::net::IpAddr;
+/// let vec = vec!["2001:0db8:0000:0000:0000:0000:0000:0001", "192.168.1.1", "fe80:0:0:0:1:0:0:1"];
+/// let ips: Vec<IpAddr> = vec.into_iter().map(|s| s.parse().unwrap()).collect();
+/// let vec_to_strings: Vec<_> = ips.iter().map(ipv6_compressed).collect();
+/// assert_eq!(vec_to_strings, vec!["2001:db8::1", "::ffff:192.168.1.1", "fe80::1:0:0:1"]);
+/// ```
+pub fn ipv6_compressed<A: ToIpAddr>(ip: A) -> String {
+    to_ipv6(ip.to_ip_addr()).to_string()
+}
+
+/// Convert a CIDR prefix length into an IPv4 subnet mask in dotted decimal, e.g.: 24 into `255.255.255.0`
+///
+/// Use .map(mask_from_prefix)
+///
+/// # Panics
+///
+/// If prefix_len is greater than 32
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![0, 8, 20, 24, 32];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(mask_from_prefix).collect();
+/// let expected = vec!["0.0.0.0", "255.0.0.0", "255.255.240.0", "255.255.255.0", "255.255.255.255"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn mask_from_prefix<P: Borrow<u8>>(prefix_len: P) -> String {
+    let prefix_len = u32::from(*prefix_len.borrow());
+    assert!(prefix_len <= 32, "prefix_len must be at most 32");
+    let mask = u32::MAX.checked_shl(32 - prefix_len).unwrap_or(0);
+    Ipv4Addr::from(mask).to_string()
+}
+
+/// Convert an IP address into its bits, with `|` at the boundary between the network and host bits
+/// of a CIDR prefix, so a prefix length that splits the address in the wrong place is easy to spot
+///
+/// IPv4 addresses are shown as octets separated by `.`, IPv6 addresses as 16 bit groups separated by `:`,
+/// the marker is placed before the separator when the boundary falls between groups.
+///
+/// # Panics
+///
+/// If prefix_len is greater than the number of bits in the address, 32 for IPv4 and 128 for IPv6
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::net::Ipv4Addr;
+/// let ip = Ipv4Addr::new(192, 168, 1, 77);
+/// assert_eq!(cidr_visualize(ip, 26), "11000000.10101000.00000001.01|001101");
+/// assert_eq!(cidr_visualize(ip, 24), "11000000.10101000.00000001|.01001101");
+/// assert_eq!(cidr_visualize(ip, 0), "|11000000.10101000.00000001.01001101");
+///
+/// let ip: std::net::IpAddr = "2001:db8::1".parse().unwrap();
+/// assert!(cidr_visualize(ip, 32).starts_with("0010000000000001:0000110110111000|:0000000000000000:"));
+/// ```
+pub fn cidr_visualize<A: ToIpAddr>(ip: A, prefix_len: u8) -> String {
+    // the bits are left aligned in a u128, so IPv4 and IPv6 are read from the same position
+    let (bits, len, group_bits, separator) = match ip.to_ip_addr() {
+        IpAddr::V4(ip) => (u128::from(u32::from(ip)) << 96, 32, 8, '.'),
+        IpAddr::V6(ip) => (u128::from(ip), 128, 16, ':'),
+    };
+    let prefix_len = usize::from(prefix_len);
+    assert!(prefix_len <= len, "prefix_len must be at most {}", len);
+    let mut visualized = String::with_capacity(len + len / group_bits + 1);
+    for i in 0..=len {
+        if i == prefix_len {
+            visualized.push('|');
+        }
+        if i == len {
+            break;
+        }
+        if i > 0 && i % group_bits == 0 {
+            visualized.push(separator);
+        }
+        visualized.push(if bits >> (127 - i) & 1 == 1 { '1' } else { '0' });
+    }
+    visualized
+}
@@ -0,0 +1,281 @@
+//! Provides functions for formatting numbers for people to read
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator of numbers into a String of:
+//!
+//! * digits grouped in thousands, e.g.: `1,234,567`.
+//! * digits grouped in the Indian numbering system, e.g.: `12,34,56,789`.
+//! * floats with the integral part grouped in thousands, e.g.: `1,234,567.89`.
+//! * money stored as integer minor units, e.g.: cents into `$1,234.56`, without float rounding.
+//!
+//! How do I convert the ASCII digits of formatted output into another numeral system, e.g.: `١٢٣`?
+//!
+//! ## Use
+//!
+//! * iter.map(group_thousands)
+//! * iter.map(group_lakh_crore)
+//! * iter.map(|f| group_thousands_float(f, 2))
+//! * iter.map(|cents| money_minor(cents, "$", 2))
+//! * iter.map(group_lakh_crore).map(digits_devanagari)
+//!
+use crate::cast::ToF64;
+use crate::fmt::BitWidth;
+use std::borrow::Borrow;
+use std::fmt::Display;
+
+/// Insert separator into a String of decimal digits, with an optional leading sign,
+/// the rightmost group has first digits, every other group has rest digits
+pub(crate) fn group_digits(number: &str, first: usize, rest: usize, separator: &str) -> String {
+    let (sign, digits) = match number.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", number),
+    };
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    let mut size = first;
+    while end > size {
+        groups.push(&digits[end - size..end]);
+        end -= size;
+        size = rest;
+    }
+    groups.push(&digits[..end]);
+    groups.reverse();
+    format!("{}{}", sign, groups.join(separator))
+}
+
+/// Convert an integer into a String with its digits grouped in thousands separated by commas
+///
+/// Use .map(group_thousands)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![0, 999, 1000, 1234567, -1234567];
+/// let vec_to_strings: Vec<_> = vec.iter().map(group_thousands).collect();
+/// let expected = vec!["0", "999", "1,000", "1,234,567", "-1,234,567"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn group_thousands<I: Display + BitWidth>(i: I) -> String {
+    group_thousands_with(i, ",")
+}
+
+/// Convert an integer into a String with its digits grouped in thousands separated by separator
+///
+/// Use .map(|i| group_thousands_with(i, "_"))
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(group_thousands_with(1234567, "."), "1.234.567");
+/// assert_eq!(group_thousands_with(u64::MAX, "_"), "18_446_744_073_709_551_615");
+/// ```
+pub fn group_thousands_with<I: Display + BitWidth, S: AsRef<str>>(i: I, separator: S) -> String {
+    group_digits(&i.to_string(), 3, 3, separator.as_ref())
+}
+
+/// Convert a float into a String with precision decimal places and the digits of the integral part
+/// grouped in thousands separated by commas, the fractional part is not grouped
+///
+/// Use .map(|f| group_thousands_float(f, 2))
+///
+/// NaN and infinity are formatted as by **format!("{}", f)**.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1234567.891, -1000.0, 999.999, 0.5];
+/// let vec_to_strings: Vec<_> = vec.iter().map(|f| group_thousands_float(f, 2)).collect();
+/// let expected = vec!["1,234,567.89", "-1,000.00", "1,000.00", "0.50"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// assert_eq!(group_thousands_float(1234.5678_f32, 0), "1,235");
+/// assert_eq!(group_thousands_float(0.000123, 6), "0.000123");
+/// ```
+pub fn group_thousands_float<F: ToF64>(f: F, precision: usize) -> String {
+    let formatted = format!("{:.*}", precision, f.to_f64());
+    match formatted.split_once('.') {
+        Some((integral, fraction)) => format!("{}.{}", group_digits(integral, 3, 3, ","), fraction),
+        None => group_digits(&formatted, 3, 3, ","),
+    }
+}
+
+/// Convert an amount of money in integer minor units, e.g.: cents, satoshi or paise, into a String with
+/// the currency symbol, the major units grouped in thousands and decimals digits of minor units
+///
+/// Use .map(|cents| money_minor(cents, "$", 2))
+///
+/// The amount is split with integer arithmetic, so it is never rounded, unlike formatting a float,
+/// a negative amount has the minus sign before the symbol, e.g.: `-$0.05`.
+///
+/// # Panics
+///
+/// If decimals is greater than 38
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let cents = vec![1234, -5, 123456789, 0];
+/// let vec_to_strings: Vec<_> = cents.iter().map(|cents| money_minor(cents, "$", 2)).collect();
+/// assert_eq!(vec_to_strings, vec!["$12.34", "-$0.05", "$1,234,567.89", "$0.00"]);
+///
+/// assert_eq!(money_minor(150_000_000, "₿", 8), "₿1.50000000");
+/// assert_eq!(money_minor(-1500, "¥", 0), "-¥1,500");
+///# assert_eq!(money_minor(i64::MIN, "$", 2), "-$92,233,720,368,547,758.08");
+///# assert_eq!(money_minor(7, "", 3), "0.007");
+/// ```
+pub fn money_minor<M: Borrow<i64>, S: AsRef<str>>(minor: M, symbol: S, decimals: u32) -> String {
+    let minor = *minor.borrow();
+    let sign = if minor < 0 { "-" } else { "" };
+    let magnitude = u128::from(minor.unsigned_abs());
+    let scale = 10_u128
+        .checked_pow(decimals)
+        .expect("decimals must be at most 38");
+    let major = group_digits(&(magnitude / scale).to_string(), 3, 3, ",");
+    if decimals == 0 {
+        format!("{}{}{}", sign, symbol.as_ref(), major)
+    } else {
+        let fraction = magnitude % scale;
+        let width = decimals as usize;
+        format!("{}{}{}.{:0width$}", sign, symbol.as_ref(), major, fraction)
+    }
+}
+
+/// Convert an integer into a String with its digits grouped in the Indian numbering system,
+/// the last 3 digits then groups of 2 (lakh, crore), separated by commas
+///
+/// Use .map(group_lakh_crore)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![999, 100000, 123456789, -10000000];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(group_lakh_crore).collect();
+/// let expected = vec!["999", "1,00,000", "12,34,56,789", "-1,00,00,000"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn group_lakh_crore<I: Display + BitWidth>(i: I) -> String {
+    group_digits(&i.to_string(), 3, 2, ",")
+}
+
+/// A set of unicode decimal digits that ASCII digits can be transliterated into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Numerals {
+    /// Arabic-Indic digits ٠١٢٣٤٥٦٧٨٩, U+0660..U+0669
+    ArabicIndic,
+    /// Extended Arabic-Indic digits ۰۱۲۳۴۵۶۷۸۹ used for Persian and Urdu, U+06F0..U+06F9
+    ExtendedArabicIndic,
+    /// Devanagari digits ०१२३४५६७८९, U+0966..U+096F
+    Devanagari,
+    /// Bengali digits ০১২৩৪৫৬৭৮৯, U+09E6..U+09EF
+    Bengali,
+    /// Thai digits ๐๑๒๓๔๕๖๗๘๙, U+0E50..U+0E59
+    Thai,
+    /// Fullwidth digits ０１２３４５６７８９ used in CJK text, U+FF10..U+FF19
+    Fullwidth,
+}
+
+impl Numerals {
+    /// The char for the digit zero, the other digits follow it consecutively
+    pub fn zero(self) -> char {
+        match self {
+            Numerals::ArabicIndic => '\u{0660}',
+            Numerals::ExtendedArabicIndic => '\u{06F0}',
+            Numerals::Devanagari => '\u{0966}',
+            Numerals::Bengali => '\u{09E6}',
+            Numerals::Thai => '\u{0E50}',
+            Numerals::Fullwidth => '\u{FF10}',
+        }
+    }
+}
+
+/// Convert a String or &str replacing every ASCII digit with the digit of another numeral system,
+/// all other chars, e.g.: separators and signs, are unchanged
+///
+/// Use .map(|s| transliterate_digits(s, Numerals::Thai))
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = transliterate_digits("Total: 1,234", Numerals::Fullwidth);
+/// assert_eq!(result, "Total: １,２３４");
+/// ```
+pub fn transliterate_digits<S: AsRef<str>>(s: S, numerals: Numerals) -> String {
+    let zero = numerals.zero() as u32;
+    s.as_ref()
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => char::from_u32(zero + digit).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
+
+/// Convert a String or &str replacing every ASCII digit with an Arabic-Indic digit
+///
+/// Use .map(digits_arabic_indic)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![123, 4050];
+/// let vec_to_strings: Vec<_> = vec.iter().map(to_string).map(digits_arabic_indic).collect();
+/// assert_eq!(vec_to_strings, vec!["١٢٣", "٤٠٥٠"]);
+/// ```
+pub fn digits_arabic_indic<S: AsRef<str>>(s: S) -> String {
+    transliterate_digits(s, Numerals::ArabicIndic)
+}
+
+/// Convert a String or &str replacing every ASCII digit with an extended Arabic-Indic (Persian) digit
+///
+/// Use .map(digits_persian)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(digits_persian("2024"), "۲۰۲۴");
+/// ```
+pub fn digits_persian<S: AsRef<str>>(s: S) -> String {
+    transliterate_digits(s, Numerals::ExtendedArabicIndic)
+}
+
+/// Convert a String or &str replacing every ASCII digit with a Devanagari digit
+///
+/// Use .map(digits_devanagari)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![123456789];
+/// let vec_to_strings: Vec<_> = vec.iter().map(group_lakh_crore).map(digits_devanagari).collect();
+/// assert_eq!(vec_to_strings, vec!["१२,३४,५६,७८९"]);
+/// ```
+pub fn digits_devanagari<S: AsRef<str>>(s: S) -> String {
+    transliterate_digits(s, Numerals::Devanagari)
+}
+
+/// Convert a String or &str replacing every ASCII digit with a Bengali digit
+///
+/// Use .map(digits_bengali)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(digits_bengali("-9.5"), "-৯.৫");
+/// ```
+pub fn digits_bengali<S: AsRef<str>>(s: S) -> String {
+    transliterate_digits(s, Numerals::Bengali)
+}
+
+/// Convert a String or &str replacing every ASCII digit with a Thai digit
+///
+/// Use .map(digits_thai)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(digits_thai("2567"), "๒๕๖๗");
+/// ```
+pub fn digits_thai<S: AsRef<str>>(s: S) -> String {
+    transliterate_digits(s, Numerals::Thai)
+}
@@ -0,0 +1,110 @@
+//! Provides functions for converting operating system paths and strings into Strings
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator of [`std::path::PathBuf`] or [`std::path::Path`] into Strings,
+//! without failing on paths that are not valid UTF-8?
+//!
+//! How do I convert [`std::ffi::OsString`]s, e.g.: from `env::vars_os()` or `read_dir`, into Strings?
+//!
+//! ## Use
+//!
+//! * iter.map(path_display)
+//! * iter.map(path_lossy)
+//! * iter.map(os_to_string_lossy)
+//!
+use std::ffi::OsStr;
+use std::path::Path;
+
+/// Convert anything that can be referenced as a [`std::path::Path`] into a String for display
+///
+/// Use .map(path_display)
+///
+/// short for **format!("{}", path.display())**, non UTF-8 sequences are replaced with �
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::path::PathBuf;
+/// let paths = vec![PathBuf::from("/tmp/a.txt"), PathBuf::from("b/c")];
+/// let vec_to_strings: Vec<_> = paths.iter().map(path_display).collect();
+/// let expected = vec!["/tmp/a.txt", "b/c"];
+/// assert_eq!(vec_to_strings, expected);
+/// let vec_to_strings_consume: Vec<_> = paths.into_iter().map(path_display).collect();
+///# assert_eq!(vec_to_strings_consume, expected);
+/// ```
+pub fn path_display<P: AsRef<Path>>(path: P) -> String {
+    format!("{}", path.as_ref().display())
+}
+
+/// Convert anything that can be referenced as a [`std::path::Path`] into a String,
+/// replacing any non UTF-8 sequences with �
+///
+/// Use .map(path_lossy)
+///
+/// short for **path.to_string_lossy().into_owned()**
+///
+/// Unlike **path.to_str()** this never fails, so the whole iterator is converted.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::path::Path;
+/// let paths = vec![Path::new("src/lib.rs"), Path::new("Cargo.toml")];
+/// let vec_to_strings: Vec<_> = paths.into_iter().map(path_lossy).collect();
+/// let expected = vec!["src/lib.rs", "Cargo.toml"];
+/// assert_eq!(vec_to_strings, expected);
+///
+///# #[cfg(unix)]
+///# {
+/// use std::os::unix::ffi::OsStrExt;
+/// let not_utf8 = Path::new(std::ffi::OsStr::from_bytes(b"bad\xFF.txt"));
+/// assert_eq!(path_lossy(not_utf8), "bad\u{FFFD}.txt");
+///# }
+/// ```
+pub fn path_lossy<P: AsRef<Path>>(path: P) -> String {
+    path.as_ref().to_string_lossy().into_owned()
+}
+
+/// Convert an [`std::ffi::OsString`] or [`std::ffi::OsStr`] into a String, replacing any non UTF-8 sequences with �
+///
+/// Use .map(os_to_string_lossy)
+///
+/// short for **os_string.to_string_lossy().into_owned()**, which cannot be written as a method path in .map()
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::ffi::OsString;
+/// let os_strings = vec![OsString::from("PATH"), OsString::from("HOME")];
+/// let vec_to_strings: Vec<_> = os_strings.iter().map(os_to_string_lossy).collect();
+/// assert_eq!(vec_to_strings, vec!["PATH", "HOME"]);
+///
+/// let file_names: Vec<String> = std::fs::read_dir(".")
+///     .unwrap()
+///     .filter_map(Result::ok)
+///     .map(|entry| entry.file_name())
+///     .map(os_to_string_lossy)
+///     .collect();
+///# assert!(file_names.iter().any(|name| name == "Cargo.toml"));
+/// ```
+pub fn os_to_string_lossy<S: AsRef<OsStr>>(s: S) -> String {
+    s.as_ref().to_string_lossy().into_owned()
+}
+
+/// Convert a pair of [`std::ffi::OsString`]s, as returned by [`std::env::vars_os`], into a pair of Strings,
+/// replacing any non UTF-8 sequences with �
+///
+/// Use .map(os_pair_to_string_lossy)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vars: Vec<(String, String)> = std::env::vars_os().map(os_pair_to_string_lossy).collect();
+///# assert_eq!(vars.len(), std::env::vars_os().count());
+/// ```
+pub fn os_pair_to_string_lossy<K: AsRef<OsStr>, V: AsRef<OsStr>>(
+    (key, value): (K, V),
+) -> (String, String) {
+    (os_to_string_lossy(key), os_to_string_lossy(value))
+}
@@ -1,3 +1,154 @@
-//! Import commonly used functions from modules in this crate: fmt
+//! Import commonly used functions from modules in this crate: align, array, cast, color, diff, encode, error, escape, ext, float, fmt, io, join, list, net, num, os, pretty, secret, serialize, template, term, text, unicode, units
 //!
-pub use crate::fmt::*;
+//! `use i2u::prelude::*` imports everything from the targeted sub-preludes below,
+//! to import fewer names use the sub-prelude you need e.g.: `use i2u::prelude::fmt::*`
+//!
+//! * [`fmt`] functions that convert a value into a String, for use in .map()
+//! * [`parse`] functions that convert a String back into a value, for use in .filter_map()
+//! * [`iter`] functions that operate on a whole collection or iterator
+//! * [`cast`] traits and functions that convert a number into another number type
+//! * [`types`] only the types and traits, without any functions
+//!
+//! # Example
+//! ```
+//! use i2u::prelude::fmt::*;
+//! let vec = vec![1, 2, 3];
+//! let vec_to_strings: Vec<_> = vec.iter().map(binary_zero_pad::<2, _>).collect();
+//! assert_eq!(vec_to_strings, vec!["01", "10", "11"]);
+//! ```
+//!
+//! ## References and values
+//!
+//! Every function that converts a single value can be used in .map() or .filter_map()
+//! after both iter(), which yields references, and into_iter(), which yields values.
+//! The exceptions are the ndarray functions, which take a reference to avoid moving the array,
+//! and the error functions, which take a reference so unsized errors such as `dyn Error` can be passed.
+//!
+//! ```
+//! use i2u::prelude::*;
+//! use std::time::Duration;
+//!
+//! macro_rules! by_ref_and_value {
+//!     ($vec:expr, $f:expr) => {{
+//!         let vec = $vec;
+//!         let by_ref: Vec<_> = vec.iter().map($f).collect();
+//!         let by_value: Vec<_> = vec.into_iter().map($f).collect();
+//!         assert_eq!(by_ref, by_value);
+//!     }};
+//! }
+//!
+//! by_ref_and_value!(vec![5_u8], to_string);
+//! by_ref_and_value!(vec![Some(5_u8)], debug);
+//! by_ref_and_value!(vec![0.1_f64], debug_float_normalized::<2, _>);
+//! by_ref_and_value!(vec![5_u8], binary_zero_pad::<8, _>);
+//! by_ref_and_value!(vec![5_u16], hex_full);
+//! by_ref_and_value!(vec![5_u16], hex_bytes);
+//! by_ref_and_value!(vec![1234567_i32], group_thousands);
+//! by_ref_and_value!(vec!["123"], digits_thai);
+//! by_ref_and_value!(vec!["x".to_string()], pad_left::<3, _>);
+//! by_ref_and_value!(vec!['é'], codepoint);
+//! by_ref_and_value!(vec!["é"], codepoints_utf8);
+//! by_ref_and_value!(vec![(1_u8, 2_u8, 3_u8)], rgb_hex);
+//! by_ref_and_value!(vec![0.5_f32], heat_color(0.0, 1.0));
+//! by_ref_and_value!(vec![2.0_f32], decibel);
+//! by_ref_and_value!(vec![51.5_f64], dms);
+//! by_ref_and_value!(vec![0x4000_i16], fixed_point::<15, _>);
+//! by_ref_and_value!(vec![Duration::from_millis(5)], duration);
+//! by_ref_and_value!(vec!["51°30'"], parse_dms);
+//! by_ref_and_value!(vec![std::path::PathBuf::from("a")], path_display);
+//! by_ref_and_value!(vec![vec![0_u8, 1]], hexdump);
+//! by_ref_and_value!(vec!["secret"], mask);
+//! ```
+//!
+
+pub mod fmt {
+    //! Import the functions that convert a value into a String, for use in .map()
+    //!
+    #[cfg(feature = "ndarray")]
+    pub use crate::array::*;
+    pub use crate::color::*;
+    pub use crate::diff::*;
+    pub use crate::encode::*;
+    pub use crate::error::*;
+    pub use crate::escape::*;
+    pub use crate::ext::*;
+    pub use crate::float::{
+        fixed, float_bits, float_to_bits_hex, float_trimmed, hex_float, scientific,
+        scientific_unicode, scientific_unicode_with, ExponentStyle, RoundingMode,
+    };
+    pub use crate::fmt::*;
+    pub use crate::io::*;
+    pub use crate::net::*;
+    pub use crate::num::*;
+    pub use crate::os::*;
+    pub use crate::pretty::*;
+    pub use crate::secret::*;
+    #[cfg(feature = "toml")]
+    pub use crate::serialize::to_toml;
+    #[cfg(feature = "yaml")]
+    pub use crate::serialize::to_yaml;
+    #[cfg(feature = "serde")]
+    pub use crate::serialize::{to_json, to_json_pretty};
+    pub use crate::template::*;
+    pub use crate::term::*;
+    pub use crate::text::*;
+    pub use crate::unicode::*;
+    pub use crate::units::{
+        calendar_month, decibel, decibel_power, decibel_power_precision, decibel_precision, dms,
+        duration, elapsed_since, fixed_point, lat_lon, Stopwatch,
+    };
+}
+
+pub mod parse {
+    //! Import the functions that convert a String back into a value, for use in .filter_map()
+    //!
+    pub use crate::encode::{base85_decode, bcd_to_string, quoted_printable_decode};
+    pub use crate::escape::parse_query_string;
+    pub use crate::float::{bits_hex_to_float, parse_hex_float};
+    #[cfg(feature = "serde")]
+    pub use crate::serialize::{from_json, from_json_ok};
+    pub use crate::units::{parse_dms, parse_lat_lon};
+}
+
+pub mod cast {
+    //! Import the traits and functions that convert a number into another number type
+    //!
+    pub use crate::cast::*;
+}
+
+pub mod iter {
+    //! Import the functions that operate on a whole collection or iterator
+    //!
+    pub use crate::align::*;
+    pub use crate::join::*;
+    pub use crate::list::*;
+}
+
+pub mod types {
+    //! Import only the types and traits, e.g.: to call the extension trait methods, without any functions
+    //!
+    //! This replaces the `minimal-prelude` feature that was requested to shrink the prelude.
+    //! Cargo unifies features across a dependency graph, so one crate enabling it would have removed
+    //! the functions from the prelude of every other crate, a sub-prelude gives the same choice per import.
+    //!
+    pub use crate::{
+        align::{Aggregation, Alignment},
+        cast::{Primitive, ToF64, ToI128},
+        color::{ToRgb, ToRgba},
+        encode::{Base85, HexString},
+        ext::{I2uBytesExt, I2uNumExt, I2uStrExt},
+        float::{ExponentStyle, FloatBits, FromFloatBits, RoundingMode},
+        fmt::{BitWidth, Endian, GrayCode, IntegerBytes, IntegerMagnitude, ScratchBuf},
+        io::{HexWriter, IndentWriter, WrapWriter},
+        join::{DisplayMap, DisplaySlice, FormattedPartition, PairRef},
+        net::ToIpAddr,
+        num::Numerals,
+        pretty::{Pretty, PrettyFormatter, PrettyOptions},
+        secret::Redacted,
+        template::{InterpolationValues, MissingKey, TemplateError, TemplateValue, TemplateValues},
+        text::MaskLengthError,
+        units::Stopwatch,
+    };
+}
+
+pub use self::{cast::*, fmt::*, iter::*, parse::*, types::*};
@@ -0,0 +1,310 @@
+//! Provides functions for pretty printing nested std collections
+//!
+//! ## The answer to the question:
+//!
+//! How do I pretty print a nested `Vec`, `HashMap`, `BTreeMap` or `Option` with:
+//!
+//! * a configurable indent width, `{:#?}` always indents by 4 spaces.
+//! * the keys of HashMaps and the values of HashSets sorted, so the output is the same on every run.
+//! * deeply nested collections elided after a maximum depth.
+//! * long collections elided after a maximum number of items.
+//!
+//! ## Use
+//!
+//! * pretty(&value)
+//! * pretty_with(&value, PrettyOptions { indent: 2, max_items: 10, ..Default::default() })
+//! * iter.map(pretty)
+//!
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Write};
+use std::hash::BuildHasher;
+
+/// The options that control how [`pretty_with`] lays out a value
+///
+/// The default is the same layout as `{:#?}` for std collections, with sorted keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrettyOptions {
+    /// The number of spaces each level of nesting is indented by, 4 by default
+    pub indent: usize,
+    /// Sort the keys of HashMaps and the values of HashSets, true by default
+    pub sort_keys: bool,
+    /// The number of levels of nested collections that are shown, deeper collections are shown as `[...]`,
+    /// unlimited by default
+    pub max_depth: usize,
+    /// The number of items shown of each collection, the rest are counted in a `... n more` line,
+    /// unlimited by default
+    pub max_items: usize,
+}
+
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        PrettyOptions {
+            indent: 4,
+            sort_keys: true,
+            max_depth: usize::MAX,
+            max_items: usize::MAX,
+        }
+    }
+}
+
+/// Writes the pretty printed text of values that implement [`Pretty`]
+#[derive(Debug)]
+pub struct PrettyFormatter<'a> {
+    out: &'a mut String,
+    options: PrettyOptions,
+    depth: usize,
+}
+
+impl PrettyFormatter<'_> {
+    /// The options the value is being formatted with
+    pub fn options(&self) -> &PrettyOptions {
+        &self.options
+    }
+
+    /// Write a value that is not a collection using its Debug format, e.g.: a number or a quoted String
+    pub fn leaf<D: Debug + ?Sized>(&mut self, d: &D) {
+        let _ = write!(self.out, "{:?}", d);
+    }
+
+    /// Write items one per line between open and close, e.g.: `[` and `]`
+    pub fn sequence<I>(&mut self, open: &str, close: &str, items: I)
+    where
+        I: IntoIterator,
+        I::Item: Pretty,
+    {
+        self.nested(open, close, items, |f, item| item.pretty_fmt(f));
+    }
+
+    /// Write key value entries one per line between `{` and `}`, in the order they are given
+    pub fn entries<I, K, V>(&mut self, entries: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Pretty,
+        V: Pretty,
+    {
+        self.nested("{", "}", entries, |f, (key, value)| {
+            key.pretty_fmt(f);
+            f.out.push_str(": ");
+            value.pretty_fmt(f);
+        });
+    }
+
+    fn nested<I, F>(&mut self, open: &str, close: &str, items: I, mut write_item: F)
+    where
+        I: IntoIterator,
+        F: FnMut(&mut Self, I::Item),
+    {
+        self.out.push_str(open);
+        let mut items = items.into_iter().peekable();
+        if items.peek().is_none() {
+            self.out.push_str(close);
+            return;
+        }
+        if self.depth >= self.options.max_depth {
+            self.out.push_str("...");
+            self.out.push_str(close);
+            return;
+        }
+        self.depth += 1;
+        for item in items.by_ref().take(self.options.max_items) {
+            self.new_line();
+            write_item(self, item);
+            self.out.push(',');
+        }
+        let more = items.count();
+        if more > 0 {
+            self.new_line();
+            let _ = write!(self.out, "... {} more", more);
+        }
+        self.depth -= 1;
+        self.new_line();
+        self.out.push_str(close);
+    }
+
+    fn new_line(&mut self) {
+        self.out.push('\n');
+        let spaces = self.depth * self.options.indent;
+        self.out.extend(std::iter::repeat_n(' ', spaces));
+    }
+}
+
+/// A value that can be pretty printed by [`pretty`] and [`pretty_with`]
+///
+/// Implemented for numbers, bool, char, str, String, Option, Vec, VecDeque, slices, arrays,
+/// HashMap, BTreeMap, HashSet, BTreeSet and references to them.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl Pretty for Point {
+///     fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+///         f.entries([("x", self.x), ("y", self.y)]);
+///     }
+/// }
+///
+/// let result = pretty(vec![Point { x: 1, y: 2 }]);
+/// assert_eq!(result, "[\n    {\n        \"x\": 1,\n        \"y\": 2,\n    },\n]");
+/// ```
+pub trait Pretty {
+    /// Write the value to the formatter
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>);
+}
+
+macro_rules! impl_pretty_leaf {
+    ($($t:ty),*) => {
+        $(impl Pretty for $t {
+            fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+                f.leaf(self);
+            }
+        })*
+    };
+}
+
+impl_pretty_leaf!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, str,
+    String
+);
+
+impl<T: Pretty + ?Sized> Pretty for &T {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        (**self).pretty_fmt(f)
+    }
+}
+
+impl<T: Pretty> Pretty for Option<T> {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        match self {
+            Some(t) => {
+                f.out.push_str("Some(");
+                t.pretty_fmt(f);
+                f.out.push(')');
+            }
+            None => f.out.push_str("None"),
+        }
+    }
+}
+
+impl<T: Pretty> Pretty for [T] {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        f.sequence("[", "]", self)
+    }
+}
+
+impl<T: Pretty, const N: usize> Pretty for [T; N] {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        f.sequence("[", "]", self)
+    }
+}
+
+impl<T: Pretty> Pretty for Vec<T> {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        f.sequence("[", "]", self)
+    }
+}
+
+impl<T: Pretty> Pretty for VecDeque<T> {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        f.sequence("[", "]", self)
+    }
+}
+
+impl<T: Pretty> Pretty for BTreeSet<T> {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        f.sequence("{", "}", self)
+    }
+}
+
+impl<T: Pretty + Ord, S: BuildHasher> Pretty for HashSet<T, S> {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        let mut values: Vec<_> = self.iter().collect();
+        if f.options.sort_keys {
+            values.sort();
+        }
+        f.sequence("{", "}", values)
+    }
+}
+
+impl<K: Pretty, V: Pretty> Pretty for BTreeMap<K, V> {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        f.entries(self)
+    }
+}
+
+impl<K: Pretty + Ord, V: Pretty, S: BuildHasher> Pretty for HashMap<K, V, S> {
+    fn pretty_fmt(&self, f: &mut PrettyFormatter<'_>) {
+        let mut entries: Vec<_> = self.iter().collect();
+        if f.options.sort_keys {
+            entries.sort_by_key(|&(key, _)| key);
+        }
+        f.entries(entries)
+    }
+}
+
+/// Convert nested std collections into a pretty printed String, laid out like `{:#?}`
+/// but with the keys of HashMaps and the values of HashSets sorted
+///
+/// Use .map(pretty)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::collections::HashMap;
+/// let map = HashMap::from([("b", vec![Some(2)]), ("a", vec![None])]);
+/// let result = pretty(&map);
+/// let expected = concat!(
+///     "{\n",
+///     "    \"a\": [\n",
+///     "        None,\n",
+///     "    ],\n",
+///     "    \"b\": [\n",
+///     "        Some(2),\n",
+///     "    ],\n",
+///     "}",
+/// );
+/// assert_eq!(result, expected);
+/// ```
+pub fn pretty<P: Pretty>(p: P) -> String {
+    pretty_with(p, PrettyOptions::default())
+}
+
+/// Convert nested std collections into a pretty printed String laid out according to options
+///
+/// Use .map(|p| pretty_with(p, options))
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::collections::BTreeMap;
+/// let map = BTreeMap::from([("evens", vec![0, 2, 4, 6]), ("nested", vec![])]);
+/// let options = PrettyOptions { indent: 2, max_items: 2, ..Default::default() };
+/// let result = pretty_with(&map, options);
+/// let expected = concat!(
+///     "{\n",
+///     "  \"evens\": [\n",
+///     "    0,\n",
+///     "    2,\n",
+///     "    ... 2 more\n",
+///     "  ],\n",
+///     "  \"nested\": [],\n",
+///     "}",
+/// );
+/// assert_eq!(result, expected);
+///
+/// let options = PrettyOptions { max_depth: 1, ..Default::default() };
+/// let result = pretty_with(vec![vec![vec![1]]], options);
+/// assert_eq!(result, "[\n    [...],\n]");
+/// ```
+pub fn pretty_with<P: Pretty>(p: P, options: PrettyOptions) -> String {
+    let mut out = String::new();
+    p.pretty_fmt(&mut PrettyFormatter {
+        out: &mut out,
+        options,
+        depth: 0,
+    });
+    out
+}
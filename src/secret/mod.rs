@@ -0,0 +1,231 @@
+//! Provides functions for formatting secrets such as keys, tokens and passwords
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a secret key into hexadecimal without leaking it through timing side channels?
+//!
+//! How do I log values such as card numbers and passwords without leaking them?
+//!
+//! ## Use
+//!
+//! * hex_string_ct(key)
+//! * iter.map(mask_except_last::<4, _>)
+//! * iter.map(mask_except_last_zeroizing::<4, _>) with the `zeroize` feature
+//! * println!("{}", Redacted(password))
+//!
+//! Enable the `zeroize` feature so formatted secrets are wiped from memory when they are dropped,
+//! rather than lingering in freed heap memory.
+//!
+use std::fmt::{Debug, Display, Formatter};
+
+/// The char used to hide each char of a masked String
+const MASK: char = '*';
+
+/// Convert a nibble (0..=15) into its lower case hexadecimal ASCII digit without branches or table lookups
+///
+/// For n <= 9 (9 - n) >> 8 is 0, otherwise it is all ones, selecting the extra offset from '0' to 'a'
+fn nibble_to_hex_ct(n: u8) -> u8 {
+    let n = n as i16;
+    (n + b'0' as i16 + (((9 - n) >> 8) & (b'a' as i16 - b'0' as i16 - 10))) as u8
+}
+
+/// Convert bytes into a lower case hexadecimal String in constant time
+///
+/// Unlike **format!("{:02x}",byte)** or a lookup table, the conversion has no branches or memory accesses
+/// that depend on the value of the bytes, so it is suitable for formatting secret keys and tokens
+/// where timing or cache side channels matter.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let key = [0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x9F];
+/// let result = hex_string_ct(key);
+/// assert_eq!(result, "deadbeef009f");
+///# let stdlib: String = key.iter().map(|b| format!("{:02x}", b)).collect();
+///# assert_eq!(result, stdlib);
+///# let all: Vec<u8> = (0..=255).collect();
+///# assert_eq!(hex_string_ct(&all), all.iter().map(|b| format!("{:02x}", b)).collect::<String>());
+/// ```
+pub fn hex_string_ct<B: AsRef<[u8]>>(bytes: B) -> String {
+    let bytes = bytes.as_ref();
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        hex.push(nibble_to_hex_ct(byte >> 4) as char);
+        hex.push(nibble_to_hex_ct(byte & 0x0F) as char);
+    }
+    hex
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+/// Convert bytes into a lower case hexadecimal String in constant time, the String is wiped from memory when dropped
+///
+/// The same as [`hex_string_ct`], but the hexadecimal key material does not linger in freed heap memory.
+/// The String is allocated once with the exact capacity, so no partial copies are left behind by reallocation.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let key = [0xDE, 0xAD, 0xBE, 0xEF];
+/// let result = hex_string_ct_zeroizing(key);
+/// assert_eq!(result.as_str(), "deadbeef");
+/// ```
+pub fn hex_string_ct_zeroizing<B: AsRef<[u8]>>(bytes: B) -> zeroize::Zeroizing<String> {
+    zeroize::Zeroizing::new(hex_string_ct(bytes))
+}
+
+/// Convert a String or &str into a String of the same number of chars, all replaced by *
+///
+/// Use .map(mask)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let passwords = vec!["hunter2", "pw"];
+/// let vec_to_strings: Vec<_> = passwords.iter().map(mask).collect();
+/// let expected = vec!["*******", "**"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn mask<S: AsRef<str>>(s: S) -> String {
+    s.as_ref().chars().map(|_| MASK).collect()
+}
+
+/// Convert a String or &str into a String with all but the last N chars replaced by *
+///
+/// Use .map(mask_except_last::<4, _>) // 4 in this case is the number of chars left visible
+///
+/// If the String has N chars or fewer it is masked completely, so a short secret is never shown in full.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let cards = vec!["4111111111111234", "123"];
+/// let vec_to_strings: Vec<_> = cards.into_iter().map(mask_except_last::<4, _>).collect();
+/// let expected = vec!["************1234", "***"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn mask_except_last<const N: usize, S: AsRef<str>>(s: S) -> String {
+    let s = s.as_ref();
+    let count = s.chars().count();
+    if count <= N {
+        return mask(s);
+    }
+    // the masked String is never longer than the input, so it is never reallocated and copied
+    let mut masked = String::with_capacity(s.len());
+    masked.extend(
+        s.chars()
+            .enumerate()
+            .map(|(i, c)| if i < count - N { MASK } else { c }),
+    );
+    masked
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+/// Convert a String or &str into a String of the same number of chars, all replaced by *,
+/// the String is wiped from memory when dropped
+///
+/// Use .map(mask_zeroizing)
+///
+/// The same as [`mask`], for code that keeps every formatted secret in a `Zeroizing` wrapper.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let passwords = vec!["hunter2", "pw"];
+/// let masked: Vec<_> = passwords.iter().map(mask_zeroizing).collect();
+/// assert_eq!(masked[0].as_str(), "*******");
+/// assert_eq!(masked[1].as_str(), "**");
+/// ```
+pub fn mask_zeroizing<S: AsRef<str>>(s: S) -> zeroize::Zeroizing<String> {
+    zeroize::Zeroizing::new(mask(s))
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+/// Convert a String or &str into a String with all but the last N chars replaced by *,
+/// the String is wiped from memory when dropped
+///
+/// Use .map(mask_except_last_zeroizing::<4, _>)
+///
+/// The same as [`mask_except_last`], but the last N chars of the secret do not linger in freed heap memory.
+/// The String is allocated once with enough capacity, so no partial copies are left behind by reallocation.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let cards = vec!["4111111111111234", "123"];
+/// let masked: Vec<_> = cards.iter().map(mask_except_last_zeroizing::<4, _>).collect();
+/// assert_eq!(masked[0].as_str(), "************1234");
+/// assert_eq!(masked[1].as_str(), "***");
+/// ```
+pub fn mask_except_last_zeroizing<const N: usize, S: AsRef<str>>(
+    s: S,
+) -> zeroize::Zeroizing<String> {
+    zeroize::Zeroizing::new(mask_except_last::<N, _>(s))
+}
+
+/// Wraps a sensitive value so that both Display and Debug show `[REDACTED]` instead of the value
+///
+/// The value is still available as the public field, so it can be passed through logging pipelines
+/// and structs deriving Debug without leaking.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// #[derive(Debug)]
+/// struct Login {
+///     user: String,
+///     password: Redacted<String>,
+/// }
+///
+/// let login = Login { user: "admin".to_string(), password: Redacted("hunter2".to_string()) };
+/// assert_eq!(debug(&login), r#"Login { user: "admin", password: [REDACTED] }"#);
+/// assert_eq!(to_string(&login.password), "[REDACTED]");
+/// assert_eq!(login.password.0, "hunter2");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Redacted<T>(pub T);
+
+impl<T> Redacted<T> {
+    /// Unwrap the sensitive value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Redacted<T> {
+    fn from(value: T) -> Self {
+        Redacted(value)
+    }
+}
+
+impl<T> Display for Redacted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl<T> Debug for Redacted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+/// Wipe the sensitive value, so a `Zeroizing<Redacted<T>>` is both hidden in logs and wiped when dropped
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use zeroize::Zeroize;
+/// let mut password = Redacted(String::from("hunter2"));
+/// password.zeroize();
+/// assert!(password.0.is_empty());
+/// ```
+impl<T: zeroize::Zeroize> zeroize::Zeroize for Redacted<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
@@ -0,0 +1,190 @@
+//! Provides functions for converting types that implement serde's `Serialize` into Strings
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator of structs into JSON lines (NDJSON)?
+//!
+//! How do I convert a config struct into YAML or TOML?
+//!
+//! How do I convert JSON lines back into structs?
+//!
+//! ## Use
+//!
+//! * iter.map(to_json)
+//! * iter.map(to_json_pretty)
+//! * iter.map(to_yaml) with the `yaml` feature
+//! * iter.map(to_toml) with the `toml` feature
+//! * lines.map(from_json::<T, _>)
+//! * lines.filter_map(from_json_ok::<T, _>)
+//!
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Convert anything that implements serde's `Serialize` trait into a single line JSON String
+///
+/// Use .map(to_json)
+///
+/// the serde equivalent of [`crate::fmt::debug`], short for **serde_json::to_string(&t).unwrap()**
+///
+/// # Panics
+///
+/// If the value cannot be represented as JSON, e.g.: a map with keys that are not Strings,
+/// in the same way that [`ToString::to_string`] panics if Display returns an error.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let points = vec![Point { x: 1, y: 2 }, Point { x: -3, y: 4 }];
+/// let ndjson: Vec<_> = points.iter().map(to_json).collect();
+/// let expected = vec![r#"{"x":1,"y":2}"#, r#"{"x":-3,"y":4}"#];
+/// assert_eq!(ndjson, expected);
+/// ```
+pub fn to_json<T: Serialize>(t: T) -> String {
+    serde_json::to_string(&t).expect("value could not be serialized as JSON")
+}
+
+/// Convert anything that implements serde's `Serialize` trait into a pretty printed multi line JSON String
+///
+/// Use .map(to_json_pretty)
+///
+/// the serde equivalent of [`crate::fmt::debug_pretty`], short for **serde_json::to_string_pretty(&t).unwrap()**
+///
+/// # Panics
+///
+/// If the value cannot be represented as JSON, e.g.: a map with keys that are not Strings
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let result = to_json_pretty(Point { x: 1, y: 2 });
+/// let expected = "{\n  \"x\": 1,\n  \"y\": 2\n}";
+/// assert_eq!(result, expected);
+/// ```
+pub fn to_json_pretty<T: Serialize>(t: T) -> String {
+    serde_json::to_string_pretty(&t).expect("value could not be serialized as JSON")
+}
+
+/// Convert a JSON String or &str into any type that implements serde's `Deserialize` trait
+///
+/// Use .map(from_json::<T, _>)
+///
+/// the inverse of [`to_json`], short for **serde_json::from_str::<T>(s)**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let ndjson = "{\"x\":1,\"y\":2}\n{\"x\":-3,\"y\":4}";
+/// let points: Result<Vec<Point>, _> = ndjson.lines().map(from_json::<Point, _>).collect();
+/// assert_eq!(points.unwrap(), vec![Point { x: 1, y: 2 }, Point { x: -3, y: 4 }]);
+///
+/// assert!(from_json::<Point, _>("not json").is_err());
+/// ```
+pub fn from_json<T: DeserializeOwned, S: AsRef<str>>(s: S) -> Result<T, serde_json::Error> {
+    serde_json::from_str(s.as_ref())
+}
+
+/// Convert a JSON String or &str into any type that implements serde's `Deserialize` trait,
+/// returning None if it is not valid
+///
+/// Use .filter_map(from_json_ok::<T, _>) to skip invalid lines
+///
+/// short for **serde_json::from_str::<T>(s).ok()**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let lines = vec!["[1,2]", "oops", "[3]"];
+/// let parsed: Vec<Vec<u32>> = lines.iter().filter_map(from_json_ok::<Vec<u32>, _>).collect();
+/// assert_eq!(parsed, vec![vec![1, 2], vec![3]]);
+/// ```
+pub fn from_json_ok<T: DeserializeOwned, S: AsRef<str>>(s: S) -> Option<T> {
+    from_json(s).ok()
+}
+
+#[cfg(feature = "yaml")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
+/// Convert anything that implements serde's `Serialize` trait into a YAML String
+///
+/// Use .map(to_yaml)
+///
+/// short for **serde_norway::to_string(&t).unwrap()**, the String ends with a new line
+///
+/// # Panics
+///
+/// If the value cannot be represented as YAML
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Server {
+///     host: String,
+///     ports: Vec<u16>,
+/// }
+///
+/// let server = Server { host: "localhost".to_string(), ports: vec![80, 443] };
+/// let result = to_yaml(&server);
+/// let expected = "host: localhost\nports:\n- 80\n- 443\n";
+/// assert_eq!(result, expected);
+/// ```
+pub fn to_yaml<T: Serialize>(t: T) -> String {
+    serde_norway::to_string(&t).expect("value could not be serialized as YAML")
+}
+
+#[cfg(feature = "toml")]
+#[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+/// Convert anything that implements serde's `Serialize` trait into a TOML String
+///
+/// Use .map(to_toml)
+///
+/// short for **toml::to_string(&t).unwrap()**, the String ends with a new line
+///
+/// # Panics
+///
+/// If the value cannot be represented as a TOML document, e.g.: it is a number or a Vec rather than a struct or map
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Server {
+///     host: String,
+///     ports: Vec<u16>,
+/// }
+///
+/// let servers = vec![Server { host: "localhost".to_string(), ports: vec![80, 443] }];
+/// let vec_to_strings: Vec<_> = servers.iter().map(to_toml).collect();
+/// let expected = vec!["host = \"localhost\"\nports = [80, 443]\n"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn to_toml<T: Serialize>(t: T) -> String {
+    toml::to_string(&t).expect("value could not be serialized as TOML")
+}
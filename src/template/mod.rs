@@ -0,0 +1,630 @@
+//! Provides a small runtime template engine with the format specs of `format!`
+//!
+//! ## The answer to the question:
+//!
+//! How do I let users configure an output format at runtime, e.g.: from a config file or CLI argument,
+//! such as `{name:<10} {addr:#010X}`, with the same format specs as `format!`?
+//!
+//! How do I substitute `${key}` placeholders in a message catalog entry with Strings, or the fields of a struct?
+//!
+//! ## Use
+//!
+//! * render("{name} at {addr:08X}", &values)
+//! * interpolate("Hello ${user}", &strings)
+//! * interpolate_with("Hello ${user}", &strings, MissingKey::Leave)
+//! * interpolate("Hello ${name}", &FieldValues::new(&user)?) with the `serde` feature
+//!
+//! The format spec is `[[fill]align][sign]['#']['0'][width]['.' precision][type]`, as documented in [`std::fmt`],
+//! where type is one of `? x X o b e E` or empty, `{{` and `}}` are literal braces.
+//! Width and precision must be numbers, the `$` and `*` forms are not supported.
+//!
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::hash::{BuildHasher, Hash};
+
+/// A value that can be substituted into a template by [`render`]
+///
+/// Created with `From` from the primitive numbers, bool, &str and String, e.g.: `42_u16.into()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateValue {
+    /// An integer and the number of bits in its type, which is needed to show negative integers
+    /// in hexadecimal, octal and binary as two's complement, like `format!`
+    Int {
+        /// The value of the integer
+        value: i128,
+        /// The number of bits in the type, e.g.: 32 for i32
+        bits: u32,
+    },
+    /// An unsigned integer too large for an i128
+    UInt(u128),
+    /// A float
+    Float(f64),
+    /// Text
+    Str(String),
+    /// A bool
+    Bool(bool),
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for TemplateValue {
+            fn from(value: $t) -> Self {
+                TemplateValue::Int { value: value as i128, bits: <$t>::BITS }
+            }
+        })*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for TemplateValue {
+    fn from(value: u128) -> Self {
+        TemplateValue::UInt(value)
+    }
+}
+
+impl From<f32> for TemplateValue {
+    fn from(value: f32) -> Self {
+        TemplateValue::Float(f64::from(value))
+    }
+}
+
+impl From<f64> for TemplateValue {
+    fn from(value: f64) -> Self {
+        TemplateValue::Float(value)
+    }
+}
+
+impl From<bool> for TemplateValue {
+    fn from(value: bool) -> Self {
+        TemplateValue::Bool(value)
+    }
+}
+
+impl From<&str> for TemplateValue {
+    fn from(value: &str) -> Self {
+        TemplateValue::Str(value.to_string())
+    }
+}
+
+impl From<String> for TemplateValue {
+    fn from(value: String) -> Self {
+        TemplateValue::Str(value)
+    }
+}
+
+/// A collection of named [`TemplateValue`]s that [`render`] looks placeholders up in
+///
+/// Implemented for HashMap, BTreeMap and slices of (name, value) pairs.
+pub trait TemplateValues {
+    /// The value named name, or None if there is no such value
+    fn value(&self, name: &str) -> Option<&TemplateValue>;
+}
+
+impl<K: Borrow<str> + Hash + Eq, S: BuildHasher> TemplateValues for HashMap<K, TemplateValue, S> {
+    fn value(&self, name: &str) -> Option<&TemplateValue> {
+        self.get(name)
+    }
+}
+
+impl<K: Borrow<str> + Ord> TemplateValues for BTreeMap<K, TemplateValue> {
+    fn value(&self, name: &str) -> Option<&TemplateValue> {
+        self.get(name)
+    }
+}
+
+impl<K: Borrow<str>> TemplateValues for [(K, TemplateValue)] {
+    fn value(&self, name: &str) -> Option<&TemplateValue> {
+        self.iter()
+            .find(|(key, _)| key.borrow() == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl<K: Borrow<str>, const N: usize> TemplateValues for [(K, TemplateValue); N] {
+    fn value(&self, name: &str) -> Option<&TemplateValue> {
+        self.as_slice().value(name)
+    }
+}
+
+impl<K: Borrow<str>> TemplateValues for Vec<(K, TemplateValue)> {
+    fn value(&self, name: &str) -> Option<&TemplateValue> {
+        self.as_slice().value(name)
+    }
+}
+
+/// The reason a template could not be rendered, with the byte offset in the template where applicable
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplateError {
+    /// A placeholder names a value that was not given
+    MissingValue(String),
+    /// A `{` without a matching `}`
+    UnclosedPlaceholder(usize),
+    /// A `}` that does not close a placeholder and is not escaped as `}}`
+    UnmatchedBrace(usize),
+    /// A format spec that cannot be parsed or that does not apply to the value, e.g.: `x` for text
+    InvalidSpec {
+        /// The name of the placeholder
+        name: String,
+        /// The format spec after the `:`
+        spec: String,
+    },
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::MissingValue(name) => write!(f, "no value for placeholder {{{}}}", name),
+            TemplateError::UnclosedPlaceholder(offset) => {
+                write!(f, "unclosed placeholder at offset {}", offset)
+            }
+            TemplateError::UnmatchedBrace(offset) => {
+                write!(
+                    f,
+                    "unmatched }} at offset {}, use }}}} for a literal brace",
+                    offset
+                )
+            }
+            TemplateError::InvalidSpec { name, spec } => {
+                write!(
+                    f,
+                    "invalid format spec {:?} for placeholder {{{}}}",
+                    spec, name
+                )
+            }
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+/// A parsed format spec
+#[derive(Default)]
+struct Spec {
+    fill: Option<char>,
+    align: Option<char>,
+    plus: bool,
+    alternate: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    kind: Option<char>,
+}
+
+/// Parse `[[fill]align][sign]['#']['0'][width]['.' precision][type]`
+fn parse_spec(spec: &str) -> Option<Spec> {
+    let mut parsed = Spec::default();
+    let chars: Vec<char> = spec.chars().collect();
+    let mut i = 0;
+    let is_align = |c: Option<&char>| matches!(c, Some('<' | '^' | '>'));
+    if is_align(chars.get(1)) {
+        parsed.fill = Some(chars[0]);
+        parsed.align = Some(chars[1]);
+        i = 2;
+    } else if is_align(chars.first()) {
+        parsed.align = Some(chars[0]);
+        i = 1;
+    }
+    match chars.get(i) {
+        Some('+') => {
+            parsed.plus = true;
+            i += 1;
+        }
+        Some('-') => i += 1,
+        _ => {}
+    }
+    if chars.get(i) == Some(&'#') {
+        parsed.alternate = true;
+        i += 1;
+    }
+    if chars.get(i) == Some(&'0') {
+        parsed.zero = true;
+        i += 1;
+    }
+    let number = |i: &mut usize| {
+        let start = *i;
+        while chars.get(*i).is_some_and(char::is_ascii_digit) {
+            *i += 1;
+        }
+        chars[start..*i]
+            .iter()
+            .collect::<String>()
+            .parse::<usize>()
+            .ok()
+    };
+    parsed.width = number(&mut i).unwrap_or(0);
+    if chars.get(i) == Some(&'.') {
+        i += 1;
+        parsed.precision = Some(number(&mut i)?);
+    }
+    match &chars[i..] {
+        [] => {}
+        [kind @ ('?' | 'x' | 'X' | 'o' | 'b' | 'e' | 'E')] => parsed.kind = Some(*kind),
+        _ => return None,
+    }
+    Some(parsed)
+}
+
+/// Format value without padding, as its optional sign, radix prefix and digits,
+/// or None if the spec does not apply to the value
+fn format_value(value: &TemplateValue, spec: &Spec) -> Option<(bool, &'static str, String)> {
+    let radix_prefix = |prefix: &'static str| if spec.alternate { prefix } else { "" };
+    let formatted = match (value, spec.kind) {
+        (TemplateValue::Str(s), None) => match spec.precision {
+            Some(precision) => (false, "", s.chars().take(precision).collect()),
+            None => (false, "", s.clone()),
+        },
+        (TemplateValue::Str(s), Some('?')) => (false, "", format!("{:?}", s)),
+        (TemplateValue::Bool(b), None | Some('?')) => (false, "", b.to_string()),
+        (TemplateValue::Float(f), None) => match spec.precision {
+            Some(precision) => (false, "", format!("{:.*}", precision, f)),
+            None => (false, "", f.to_string()),
+        },
+        (TemplateValue::Float(f), Some('?')) => (false, "", format!("{:?}", f)),
+        (TemplateValue::Float(f), Some(kind @ ('e' | 'E'))) => {
+            let exp = match spec.precision {
+                Some(precision) => format!("{:.*e}", precision, f),
+                None => format!("{:e}", f),
+            };
+            (
+                false,
+                "",
+                if kind == 'E' { exp.to_uppercase() } else { exp },
+            )
+        }
+        (
+            TemplateValue::Int { .. } | TemplateValue::UInt(_),
+            Some(kind @ ('x' | 'X' | 'o' | 'b')),
+        ) => {
+            let bits = match *value {
+                TemplateValue::Int { value, bits } if value < 0 => {
+                    (value as u128) & (u128::MAX >> (128 - bits))
+                }
+                TemplateValue::Int { value, .. } => value as u128,
+                TemplateValue::UInt(value) => value,
+                _ => unreachable!(),
+            };
+            match kind {
+                'x' => (false, radix_prefix("0x"), format!("{:x}", bits)),
+                'X' => (false, radix_prefix("0x"), format!("{:X}", bits)),
+                'o' => (false, radix_prefix("0o"), format!("{:o}", bits)),
+                _ => (false, radix_prefix("0b"), format!("{:b}", bits)),
+            }
+        }
+        (TemplateValue::Int { value, .. }, None | Some('?')) => (false, "", value.to_string()),
+        (TemplateValue::UInt(value), None | Some('?')) => (false, "", value.to_string()),
+        (TemplateValue::Int { value, .. }, Some(kind @ ('e' | 'E'))) => {
+            let exp = match spec.precision {
+                Some(precision) => format!("{:.*e}", precision, value),
+                None => format!("{:e}", value),
+            };
+            (
+                false,
+                "",
+                if kind == 'E' { exp.to_uppercase() } else { exp },
+            )
+        }
+        (TemplateValue::UInt(value), Some(kind @ ('e' | 'E'))) => {
+            let exp = match spec.precision {
+                Some(precision) => format!("{:.*e}", precision, value),
+                None => format!("{:e}", value),
+            };
+            (
+                false,
+                "",
+                if kind == 'E' { exp.to_uppercase() } else { exp },
+            )
+        }
+        _ => return None,
+    };
+    let (_, prefix, digits) = formatted;
+    match digits.strip_prefix('-') {
+        Some(digits) if !matches!(value, TemplateValue::Str(_)) => {
+            Some((true, prefix, digits.to_string()))
+        }
+        _ => Some((false, prefix, digits)),
+    }
+}
+
+/// Format value with the spec, padded to its width
+fn format_with_spec(value: &TemplateValue, spec: &Spec) -> Option<String> {
+    let (negative, prefix, digits) = format_value(value, spec)?;
+    let numeric = !matches!(value, TemplateValue::Str(_) | TemplateValue::Bool(_));
+    let sign = match (negative, spec.plus && numeric) {
+        (true, _) => "-",
+        (false, true) => "+",
+        (false, false) => "",
+    };
+    let len = sign.chars().count() + prefix.len() + digits.chars().count();
+    let padding = spec.width.saturating_sub(len);
+    if spec.zero && numeric {
+        return Some(format!(
+            "{}{}{}{}",
+            sign,
+            prefix,
+            "0".repeat(padding),
+            digits
+        ));
+    }
+    let align = spec.align.unwrap_or(if numeric { '>' } else { '<' });
+    let (left, right) = match align {
+        '<' => (0, padding),
+        '^' => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+    let fill = spec.fill.unwrap_or(' ');
+    let mut formatted = String::with_capacity(len + padding);
+    formatted.extend(std::iter::repeat_n(fill, left));
+    formatted.push_str(sign);
+    formatted.push_str(prefix);
+    formatted.push_str(&digits);
+    formatted.extend(std::iter::repeat_n(fill, right));
+    Some(formatted)
+}
+
+/// Render a template, replacing each `{name}` or `{name:spec}` placeholder with the named value
+/// formatted with the spec, which has the same syntax and meaning as in `format!`
+///
+/// Useful when the output format is only known at runtime, e.g.: from a config file or CLI argument,
+/// the template is parsed on every call. Negative integers are shown in hexadecimal, octal and binary
+/// as two's complement of the width of their original type, like `format!`.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::collections::HashMap;
+/// let values = HashMap::from([
+///     ("name", TemplateValue::from("eth0")),
+///     ("addr", 0xC0A8_0001_u32.into()),
+///     ("mtu", 1500.into()),
+///     ("load", 0.4567.into()),
+/// ]);
+/// let result = render("{name:<6}|{addr:#010X}|{mtu:>6}|{load:.1}|{{literal}}", &values).unwrap();
+/// assert_eq!(result, "eth0  |0xC0A80001|  1500|0.5|{literal}");
+///
+/// let values = [("n", TemplateValue::from(-26_i8))];
+/// assert_eq!(render("{n} {n:x} {n:+05} {n:*^7b}", &values).unwrap(), "-26 e6 -0026 11100110");
+///
+/// assert_eq!(render("{missing}", &values), Err(TemplateError::MissingValue("missing".to_string())));
+///# let check = |template: &str, value: TemplateValue| {
+///#     render(template, &[("v", value)]).unwrap()
+///# };
+///# assert_eq!(check("{v:08.3}", (-3.14159).into()), format!("{:08.3}", -3.14159));
+///# assert_eq!(check("{v:+}", 5_u8.into()), format!("{:+}", 5_u8));
+///# assert_eq!(check("{v:#o}", 8_u8.into()), format!("{:#o}", 8_u8));
+///# assert_eq!(check("{v:#06b}", 5_u8.into()), format!("{:#06b}", 5_u8));
+///# assert_eq!(check("{v:^9}", "mid".into()), format!("{:^9}", "mid"));
+///# assert_eq!(check("{v:.2}", "truncate".into()), format!("{:.2}", "truncate"));
+///# assert_eq!(check("{v:?}", "quote\"d".into()), format!("{:?}", "quote\"d"));
+///# assert_eq!(check("{v:e}", 1234.5.into()), format!("{:e}", 1234.5));
+///# assert_eq!(check("{v:.2E}", 1234_i32.into()), format!("{:.2E}", 1234_i32));
+///# assert_eq!(check("{v:x}", (-1_i64).into()), format!("{:x}", -1_i64));
+///# assert_eq!(check("{v:X}", u128::MAX.into()), format!("{:X}", u128::MAX));
+///# assert_eq!(check("{v:>5}", true.into()), format!("{:>5}", true));
+///# assert_eq!(check("{v:-<6}", 42.into()), format!("{:-<6}", 42));
+///# assert_eq!(check("{v:?}", 1.0.into()), format!("{:?}", 1.0));
+///# assert_eq!(render("{v", &[("v", 1.into())]), Err(TemplateError::UnclosedPlaceholder(0)));
+///# assert_eq!(render("a}b", &[("v", 1.into())]), Err(TemplateError::UnmatchedBrace(1)));
+///# assert!(matches!(render("{v:x}", &[("v", "s".into())]), Err(TemplateError::InvalidSpec { .. })));
+///# assert!(matches!(render("{v:5q}", &[("v", 1.into())]), Err(TemplateError::InvalidSpec { .. })));
+/// ```
+pub fn render<V: TemplateValues + ?Sized>(
+    template: &str,
+    values: &V,
+) -> Result<String, TemplateError> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(index) = rest.find(['{', '}']) {
+        let offset = template.len() - rest.len() + index;
+        rendered.push_str(&rest[..index]);
+        let brace = &rest[index..];
+        if let Some(after) = brace.strip_prefix("{{") {
+            rendered.push('{');
+            rest = after;
+        } else if let Some(after) = brace.strip_prefix("}}") {
+            rendered.push('}');
+            rest = after;
+        } else if brace.starts_with('}') {
+            return Err(TemplateError::UnmatchedBrace(offset));
+        } else {
+            let end = brace
+                .find('}')
+                .ok_or(TemplateError::UnclosedPlaceholder(offset))?;
+            let placeholder = &brace[1..end];
+            let (name, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+            let name = name.trim();
+            let value = values
+                .value(name)
+                .ok_or_else(|| TemplateError::MissingValue(name.to_string()))?;
+            let formatted = parse_spec(spec)
+                .and_then(|parsed| format_with_spec(value, &parsed))
+                .ok_or_else(|| TemplateError::InvalidSpec {
+                    name: name.to_string(),
+                    spec: spec.to_string(),
+                })?;
+            rendered.push_str(&formatted);
+            rest = &brace[end + 1..];
+        }
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// What [`interpolate_with`] does with a `${key}` placeholder whose key has no value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MissingKey {
+    /// Fail with [`TemplateError::MissingValue`]
+    #[default]
+    Error,
+    /// Leave the placeholder in the output unchanged, e.g.: `${key}`
+    Leave,
+    /// Replace the placeholder with nothing
+    Empty,
+}
+
+/// A collection of named Strings that [`interpolate`] looks `${key}` placeholders up in
+///
+/// Implemented for HashMap and BTreeMap with String or &str values, and for `FieldValues`
+/// with the `serde` feature.
+pub trait InterpolationValues {
+    /// The text for key, or None if there is no such key
+    fn text(&self, key: &str) -> Option<&str>;
+}
+
+impl<K: Borrow<str> + Hash + Eq, V: AsRef<str>, S: BuildHasher> InterpolationValues
+    for HashMap<K, V, S>
+{
+    fn text(&self, key: &str) -> Option<&str> {
+        self.get(key).map(AsRef::as_ref)
+    }
+}
+
+impl<K: Borrow<str> + Ord, V: AsRef<str>> InterpolationValues for BTreeMap<K, V> {
+    fn text(&self, key: &str) -> Option<&str> {
+        self.get(key).map(AsRef::as_ref)
+    }
+}
+
+/// The fields of a struct, or entries of a map, that implements serde's `Serialize`, as Strings,
+/// for use with [`interpolate`]
+///
+/// String fields are used as is, any other field as its JSON, e.g.: `42`, `true` or `[1,2]`.
+/// A value that does not serialize as a JSON object has no fields.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use serde::Serialize;
+/// #[derive(Serialize)]
+/// struct Order {
+///     customer: String,
+///     items: u32,
+/// }
+/// let order = Order { customer: "Ada".to_string(), items: 3 };
+/// let fields = FieldValues::new(&order).unwrap();
+/// let message = interpolate("${customer} ordered ${items} items", &fields);
+/// assert_eq!(message.unwrap(), "Ada ordered 3 items");
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FieldValues(pub HashMap<String, String>);
+
+#[cfg(feature = "serde")]
+impl FieldValues {
+    /// The fields of value, or the error if it cannot be serialized as JSON, e.g.: a map with non string keys
+    ///
+    /// # Example
+    /// ```
+    /// use i2u::prelude::*;
+    /// use std::collections::HashMap;
+    /// let by_name = HashMap::from([("name", "Ada")]);
+    /// assert_eq!(FieldValues::new(&by_name).unwrap().text("name"), Some("Ada"));
+    ///
+    /// let by_id = HashMap::from([((1, 2), "Ada")]);
+    /// assert!(FieldValues::new(&by_id).is_err());
+    /// ```
+    pub fn new<T: serde::Serialize + ?Sized>(value: &T) -> Result<Self, serde_json::Error> {
+        let value = serde_json::to_value(value)?;
+        let fields = match value {
+            serde_json::Value::Object(fields) => fields
+                .into_iter()
+                .map(|(key, value)| match value {
+                    serde_json::Value::String(s) => (key, s),
+                    value => (key, value.to_string()),
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
+        Ok(FieldValues(fields))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl InterpolationValues for FieldValues {
+    fn text(&self, key: &str) -> Option<&str> {
+        self.0.text(key)
+    }
+}
+
+/// Substitute every `${key}` placeholder in template with the value of key,
+/// failing with [`TemplateError::MissingValue`] if a key has no value
+///
+/// short for **interpolate_with(template, values, MissingKey::Error)**,
+/// unlike [`render`] there are no format specs, so the values are plain Strings as found in message catalogs.
+/// `$${` is a literal `${`, a `$` that is not followed by `{` is left as is.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::collections::HashMap;
+/// let strings = HashMap::from([("user", "Ada".to_string()), ("count", "3".to_string())]);
+/// let message = interpolate("Hello ${user}, you have ${count} new messages", &strings);
+/// assert_eq!(message.unwrap(), "Hello Ada, you have 3 new messages");
+///
+/// let message = interpolate("${greeting} ${user}", &strings);
+/// assert_eq!(message, Err(TemplateError::MissingValue("greeting".to_string())));
+///
+/// assert_eq!(interpolate("costs $5, write $${user}", &strings).unwrap(), "costs $5, write ${user}");
+///# assert_eq!(interpolate("${user", &strings), Err(TemplateError::UnclosedPlaceholder(0)));
+///# assert_eq!(interpolate("$", &strings).unwrap(), "$");
+///# assert_eq!(interpolate("${ user }$", &strings).unwrap(), "Ada$");
+/// ```
+pub fn interpolate<V: InterpolationValues + ?Sized>(
+    template: &str,
+    values: &V,
+) -> Result<String, TemplateError> {
+    interpolate_with(template, values, MissingKey::Error)
+}
+
+/// Substitute every `${key}` placeholder in template with the value of key,
+/// with missing deciding what happens when a key has no value
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::collections::BTreeMap;
+/// let strings = BTreeMap::from([("user", "Ada")]);
+/// let leave = interpolate_with("${greeting} ${user}", &strings, MissingKey::Leave);
+/// assert_eq!(leave.unwrap(), "${greeting} Ada");
+/// let empty = interpolate_with("${greeting} ${user}", &strings, MissingKey::Empty);
+/// assert_eq!(empty.unwrap(), " Ada");
+/// ```
+pub fn interpolate_with<V: InterpolationValues + ?Sized>(
+    template: &str,
+    values: &V,
+    missing: MissingKey,
+) -> Result<String, TemplateError> {
+    let mut interpolated = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(index) = rest.find('$') {
+        let offset = template.len() - rest.len() + index;
+        interpolated.push_str(&rest[..index]);
+        let dollar = &rest[index..];
+        if let Some(after) = dollar.strip_prefix("$${") {
+            interpolated.push_str("${");
+            rest = after;
+        } else if let Some(after) = dollar.strip_prefix("${") {
+            let end = after
+                .find('}')
+                .ok_or(TemplateError::UnclosedPlaceholder(offset))?;
+            let key = after[..end].trim();
+            match (values.text(key), missing) {
+                (Some(text), _) => interpolated.push_str(text),
+                (None, MissingKey::Error) => {
+                    return Err(TemplateError::MissingValue(key.to_string()))
+                }
+                (None, MissingKey::Leave) => interpolated.push_str(&dollar[..end + 3]),
+                (None, MissingKey::Empty) => {}
+            }
+            rest = &after[end + 1..];
+        } else {
+            interpolated.push('$');
+            rest = &dollar[1..];
+        }
+    }
+    interpolated.push_str(rest);
+    Ok(interpolated)
+}
@@ -0,0 +1,148 @@
+//! Provides functions for formatting output for terminals
+//!
+//! ## The answer to the question:
+//!
+//! How do I make file paths and IDs printed in a terminal clickable?
+//!
+//! How do I truncate or wrap log lines and table rows to the width of the terminal, so they do not wrap raggedly?
+//!
+//! ## Use
+//!
+//! * hyperlink(text, url)
+//! * hyperlink_with(text, url, supports_hyperlinks)
+//! * iter.map(truncate_to_terminal)
+//! * wrap_to_terminal(text)
+//!
+//! Enable the `terminal` feature to query the width of the terminal.
+//!
+#[cfg(feature = "terminal")]
+use crate::align::{truncate_to, wrap_to};
+use std::fmt::Display;
+
+/// The OSC 8 string terminator, ESC \
+const ST: &str = "\x1b\\";
+
+/// Convert text and a URL into a terminal hyperlink using the OSC 8 escape sequence
+///
+/// Terminals that support OSC 8 show only the text, which opens the URL when clicked.
+///
+/// short for **format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = hyperlink("docs", "https://docs.rs/i2u");
+/// let expected = "\x1b]8;;https://docs.rs/i2u\x1b\\docs\x1b]8;;\x1b\\";
+/// assert_eq!(result, expected);
+///
+/// let ids = vec![12, 34];
+/// let links: Vec<_> = ids
+///     .iter()
+///     .map(|id| hyperlink(id, format!("https://example.com/issues/{}", id)))
+///     .collect();
+///# assert_eq!(links.len(), 2);
+/// ```
+pub fn hyperlink<T: Display, U: Display>(text: T, url: U) -> String {
+    format!("\x1b]8;;{}{}{}\x1b]8;;{}", url, ST, text, ST)
+}
+
+/// Convert text and a URL into a terminal hyperlink, or when hyperlinks are not enabled into plain text
+///
+/// The plain text fallback is `text (url)`, or just the url if it is the same as the text,
+/// so the URL is not lost when the output is redirected to a file or a terminal without OSC 8 support.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = hyperlink_with("docs", "https://docs.rs/i2u", false);
+/// assert_eq!(result, "docs (https://docs.rs/i2u)");
+///
+/// let result = hyperlink_with("https://docs.rs", "https://docs.rs", false);
+/// assert_eq!(result, "https://docs.rs");
+///
+/// assert_eq!(hyperlink_with("a", "b", true), hyperlink("a", "b"));
+/// ```
+pub fn hyperlink_with<T: Display, U: Display>(text: T, url: U, enabled: bool) -> String {
+    if enabled {
+        return hyperlink(text, url);
+    }
+    let (text, url) = (text.to_string(), url.to_string());
+    if text == url {
+        url
+    } else {
+        format!("{} ({})", text, url)
+    }
+}
+
+/// The width used when standard output is not a terminal and the `COLUMNS` environment variable is not set
+#[cfg(feature = "terminal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "terminal")))]
+pub const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+/// The number of columns of the terminal that standard output is connected to
+///
+/// When standard output is redirected, the `COLUMNS` environment variable is used if it is set,
+/// otherwise [`DEFAULT_TERMINAL_WIDTH`].
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let width = terminal_width();
+/// assert!(width > 0);
+/// ```
+#[cfg(feature = "terminal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "terminal")))]
+pub fn terminal_width() -> usize {
+    terminal_size::terminal_size()
+        .map(|(terminal_size::Width(width), _)| usize::from(width))
+        .or_else(|| std::env::var("COLUMNS").ok()?.trim().parse().ok())
+        .filter(|&width| width > 0)
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+}
+
+/// Truncate each line of a String or &str to the width of the terminal, ending with … if it was too long
+///
+/// Use .map(truncate_to_terminal) for table rows and log lines that must stay on one line
+///
+/// The width is queried on every call with [`terminal_width`], so a resized terminal is taken into account.
+/// With the `unicode-width` feature wide chars are counted as 2 columns.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let long = "x".repeat(terminal_width() + 10);
+/// let rows = vec!["short", long.as_str()];
+/// let truncated: Vec<_> = rows.iter().map(truncate_to_terminal).collect();
+/// assert_eq!(truncated[0], "short");
+/// assert_eq!(truncated[1].chars().count(), terminal_width());
+/// assert!(truncated[1].ends_with('…'));
+/// ```
+#[cfg(feature = "terminal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "terminal")))]
+pub fn truncate_to_terminal<S: AsRef<str>>(s: S) -> String {
+    let width = terminal_width();
+    s.as_ref()
+        .lines()
+        .map(|line| truncate_to(line, width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wrap a String or &str at spaces so no line is wider than the terminal
+///
+/// Existing new lines are kept, words wider than the terminal are split.
+/// With the `unicode-width` feature wide chars are counted as 2 columns.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let words = vec!["word"; terminal_width()].join(" ");
+/// let wrapped = wrap_to_terminal(&words);
+/// assert!(wrapped.lines().count() > 1);
+/// assert!(wrapped.lines().all(|line| line.chars().count() <= terminal_width()));
+/// ```
+#[cfg(feature = "terminal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "terminal")))]
+pub fn wrap_to_terminal<S: AsRef<str>>(s: S) -> String {
+    wrap_to(s.as_ref(), terminal_width())
+}
@@ -0,0 +1,358 @@
+//! Provides functions for cleaning up text
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator of Strings into Strings with:
+//!
+//! * the leading and trailing whitespace removed.
+//! * every run of whitespace collapsed into a single space.
+//!
+//! How do I avoid allocating when most of the Strings are already clean?
+//!
+//! How do I normalize text with a mix of Unix `\n`, Windows `\r\n` and old Mac `\r` line endings?
+//!
+//! How do I replace tabs with spaces, or spaces with tabs, keeping the text lined up, before aligning or wrapping it?
+//!
+//! How do I format a phone number, serial number, IBAN or card number with a mask, e.g.: `(###) ###-####`?
+//!
+//! ## Use
+//!
+//! * iter.map(trim_cow)
+//! * iter.map(normalize_whitespace)
+//! * iter.map(normalize_whitespace_cow)
+//! * iter.map(to_lf)
+//! * lines_any(text)
+//! * iter.map(|n| format_mask(n, "(###) ###-####"))
+//! * iter.map(|s| expand_tabs(s, 8))
+//! * iter.map(|s| unexpand(s, 8))
+//!
+use crate::align::char_width;
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Convert a &str or &String into a Cow with the leading and trailing whitespace removed,
+/// which always borrows the input
+///
+/// Use .map(trim_cow) where the result is combined with other functions returning `Cow<str>`,
+/// unlike `str::trim` it accepts &String as well as &str, so can be used after iter() on a `Vec<String>`.
+///
+/// short for **Cow::Borrowed(s.trim())**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::borrow::Cow;
+/// let vec = vec!["  padded\n".to_string(), "clean".to_string()];
+/// let trimmed: Vec<Cow<str>> = vec.iter().map(trim_cow).collect();
+/// assert_eq!(trimmed, vec!["padded", "clean"]);
+/// assert!(trimmed.iter().all(|t| matches!(t, Cow::Borrowed(_))));
+/// ```
+pub fn trim_cow<S: AsRef<str> + ?Sized>(s: &S) -> Cow<'_, str> {
+    Cow::Borrowed(s.as_ref().trim())
+}
+
+/// Convert a String or &str into a String with the leading and trailing whitespace removed
+/// and every other run of whitespace, including new lines and tabs, collapsed into a single space
+///
+/// Use .map(normalize_whitespace)
+///
+/// short for **s.split_whitespace().collect::<Vec<_>>().join(" ")**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["  a \t b\n\nc  ", "d"];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(normalize_whitespace).collect();
+/// assert_eq!(vec_to_strings, vec!["a b c", "d"]);
+/// ```
+pub fn normalize_whitespace<S: AsRef<str>>(s: S) -> String {
+    normalize_whitespace_cow(s.as_ref()).into_owned()
+}
+
+/// Convert a &str or &String into whitespace normalized text, like [`normalize_whitespace`],
+/// but borrowing the input when it only needs trimming
+///
+/// Use .map(normalize_whitespace_cow) to skip allocating in hot paths where most of the Strings are already clean
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::borrow::Cow;
+/// let vec = vec![" one space ", "two  spaces"];
+/// let normalized: Vec<_> = vec.iter().map(normalize_whitespace_cow).collect();
+/// assert!(matches!(normalized[0], Cow::Borrowed("one space")));
+/// assert!(matches!(normalized[1], Cow::Owned(_)));
+/// assert_eq!(normalized, vec!["one space", "two spaces"]);
+/// ```
+pub fn normalize_whitespace_cow<S: AsRef<str> + ?Sized>(s: &S) -> Cow<'_, str> {
+    let trimmed = s.as_ref().trim();
+    let mut previous_was_space = false;
+    let is_normalized = trimmed.chars().all(|c| {
+        let ok = !c.is_whitespace() || (c == ' ' && !previous_was_space);
+        previous_was_space = c.is_whitespace();
+        ok
+    });
+    if is_normalized {
+        Cow::Borrowed(trimmed)
+    } else {
+        Cow::Owned(trimmed.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// Split a &str into lines ending in `\n`, `\r\n` or a lone `\r`, without the line endings
+///
+/// Like `str::lines`, but a lone `\r` also ends a line, so text from any platform is split the same way.
+/// A final line ending does not produce an empty last line.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let text = "unix\nwindows\r\nmac\rlast\n";
+/// let lines: Vec<_> = lines_any(text).collect();
+/// assert_eq!(lines, vec!["unix", "windows", "mac", "last"]);
+/// assert_eq!(text.lines().count(), 3);
+/// ```
+pub fn lines_any(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(s).filter(|s| !s.is_empty());
+    std::iter::from_fn(move || {
+        let text = rest?;
+        match text.find(['\n', '\r']) {
+            Some(end) => {
+                let ending = if text[end..].starts_with("\r\n") {
+                    2
+                } else {
+                    1
+                };
+                rest = Some(&text[end + ending..]).filter(|s| !s.is_empty());
+                Some(&text[..end])
+            }
+            None => {
+                rest = None;
+                Some(text)
+            }
+        }
+    })
+}
+
+/// Convert a String or &str with any mix of `\r\n`, `\r` and `\n` line endings into a String with only `\n`
+///
+/// Use .map(to_lf)
+///
+/// short for **s.replace("\r\n", "\n").replace('\r', "\n")**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["a\r\nb\rc\n", "plain"];
+/// let vec_to_strings: Vec<_> = vec.iter().map(to_lf).collect();
+/// assert_eq!(vec_to_strings, vec!["a\nb\nc\n", "plain"]);
+/// ```
+pub fn to_lf<S: AsRef<str>>(s: S) -> String {
+    to_line_ending(s.as_ref(), "\n")
+}
+
+/// Convert a String or &str with any mix of `\r\n`, `\r` and `\n` line endings into a String with only `\r\n`
+///
+/// Use .map(to_crlf), e.g.: before writing text for Windows tools or protocols such as HTTP and SMTP
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = to_crlf("a\nb\r\nc\rd");
+/// assert_eq!(result, "a\r\nb\r\nc\r\nd");
+/// ```
+pub fn to_crlf<S: AsRef<str>>(s: S) -> String {
+    to_line_ending(s.as_ref(), "\r\n")
+}
+
+/// Replace every `\r\n`, `\r` and `\n` line ending with ending
+fn to_line_ending(s: &str, ending: &str) -> String {
+    let mut normalized = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                normalized.push_str(ending);
+            }
+            '\n' => normalized.push_str(ending),
+            _ => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+/// The reason [`format_mask`] could not fill a mask, the number of chars in the value
+/// was not the same as the number of `#` placeholders in the mask
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaskLengthError {
+    /// The number of `#` placeholders in the mask
+    pub placeholders: usize,
+    /// The number of chars in the value
+    pub chars: usize,
+}
+
+impl Display for MaskLengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the mask has {} placeholders but the value has {} chars",
+            self.placeholders, self.chars
+        )
+    }
+}
+
+impl Error for MaskLengthError {}
+
+/// Convert a value into a String by replacing each `#` in mask with the next char of the value, left to right,
+/// every other char of the mask is copied as is
+///
+/// Use .map(|n| format_mask(n, "(###) ###-####"))
+///
+/// The value is anything that implements Display, e.g.: an integer or &str, every char of it is used,
+/// so remove spaces and separators from the input first. Fails if the number of chars is not the same
+/// as the number of placeholders, rather than silently dropping or leaving out digits.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let phones = vec![5551234567_u64, 2125550199];
+/// let vec_to_strings: Vec<_> = phones.iter().map(|n| format_mask(n, "(###) ###-####").unwrap()).collect();
+/// assert_eq!(vec_to_strings, vec!["(555) 123-4567", "(212) 555-0199"]);
+///
+/// let iban = format_mask("GB82WEST12345698765432", "#### #### #### #### #### ##");
+/// assert_eq!(iban.unwrap(), "GB82 WEST 1234 5698 7654 32");
+///
+/// let error = format_mask(12345, "###-###").unwrap_err();
+/// assert_eq!(error, MaskLengthError { placeholders: 6, chars: 5 });
+/// assert_eq!(error.to_string(), "the mask has 6 placeholders but the value has 5 chars");
+///# assert!(format_mask("1234567", "###-###").is_err());
+///# assert_eq!(format_mask("", "--").unwrap(), "--");
+/// ```
+pub fn format_mask<D: Display, M: AsRef<str>>(
+    value: D,
+    mask: M,
+) -> Result<String, MaskLengthError> {
+    let value = value.to_string();
+    let mask = mask.as_ref();
+    let placeholders = mask.chars().filter(|&c| c == '#').count();
+    let chars = value.chars().count();
+    if placeholders != chars {
+        return Err(MaskLengthError {
+            placeholders,
+            chars,
+        });
+    }
+    let mut value_chars = value.chars();
+    Ok(mask
+        .chars()
+        .map(|c| match c {
+            '#' => value_chars.next().expect("the lengths were checked"),
+            c => c,
+        })
+        .collect())
+}
+
+/// Convert a String or &str into a String with each tab replaced by the spaces up to the next tab stop,
+/// every tab_width columns, like the `expand` command
+///
+/// Use .map(|s| expand_tabs(s, 8))
+///
+/// The column is counted from the start of each line, so tabs after text line up,
+/// unlike **s.replace('\t', "    ")**. With the `unicode-width` feature wide chars are counted as 2 columns.
+///
+/// # Panics
+///
+/// If tab_width is 0
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let text = "name\tage\nalexander\t42";
+/// assert_eq!(expand_tabs(text, 8), "name    age\nalexander       42");
+/// assert_eq!(expand_tabs("\tx\ty", 4), "    x   y");
+/// ```
+pub fn expand_tabs<S: AsRef<str>>(s: S, tab_width: usize) -> String {
+    assert!(tab_width > 0, "tab_width must be greater than 0");
+    let s = s.as_ref();
+    let mut expanded = String::with_capacity(s.len());
+    let mut column = 0;
+    for c in s.chars() {
+        match c {
+            '\t' => {
+                let spaces = tab_width - column % tab_width;
+                expanded.extend(std::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+            '\n' | '\r' => {
+                expanded.push(c);
+                column = 0;
+            }
+            c => {
+                expanded.push(c);
+                column += char_width(c);
+            }
+        }
+    }
+    expanded
+}
+
+/// Convert a String or &str into a String with each run of two or more spaces that ends at a tab stop,
+/// every tab_width columns, replaced by a tab, like `unexpand -a`
+///
+/// Use .map(|s| unexpand(s, 8))
+///
+/// The inverse of [`expand_tabs`], a single space before a tab stop is kept, as is done by `unexpand`,
+/// and spaces before a tab are merged into it.
+///
+/// # Panics
+///
+/// If tab_width is 0
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// assert_eq!(unexpand("name    age\nalexander       42", 8), "name\tage\nalexander\t42");
+/// assert_eq!(unexpand("        indented    x", 4), "\t\tindented\tx");
+/// assert_eq!(unexpand("abc def", 4), "abc def");
+///# assert_eq!(unexpand("ab \tc", 4), "ab\tc");
+///# assert_eq!(unexpand("a   ", 8), "a   ");
+///# let text = "a\tbc\t\tdef\n\tg h";
+///# assert_eq!(unexpand(expand_tabs(text, 4), 4), text);
+/// ```
+pub fn unexpand<S: AsRef<str>>(s: S, tab_width: usize) -> String {
+    assert!(tab_width > 0, "tab_width must be greater than 0");
+    let s = s.as_ref();
+    let mut unexpanded = String::with_capacity(s.len());
+    let mut column = 0;
+    let mut spaces = 0;
+    for c in s.chars() {
+        match c {
+            ' ' => {
+                spaces += 1;
+                column += 1;
+                if column % tab_width == 0 {
+                    unexpanded.push(if spaces > 1 { '\t' } else { ' ' });
+                    spaces = 0;
+                }
+            }
+            '\t' => {
+                spaces = 0;
+                unexpanded.push('\t');
+                column += tab_width - column % tab_width;
+            }
+            c => {
+                unexpanded.extend(std::iter::repeat_n(' ', spaces));
+                spaces = 0;
+                unexpanded.push(c);
+                column = match c {
+                    '\n' | '\r' => 0,
+                    c => column + char_width(c),
+                };
+            }
+        }
+    }
+    unexpanded.extend(std::iter::repeat_n(' ', spaces));
+    unexpanded
+}
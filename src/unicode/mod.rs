@@ -0,0 +1,184 @@
+//! Provides functions for inspecting unicode text
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert chars into their unicode codepoint, e.g.: `U+1F600`, to debug encoding issues?
+//!
+//! What exactly is in this String?
+//!
+//! How do I filter the chars of a String by class without a closure, whether the iterator yields char or &char?
+//!
+//! ## Use
+//!
+//! * string.chars().map(codepoint)
+//! * string.chars().map(codepoint_with_char)
+//! * iter.map(codepoints)
+//! * string.chars().filter(is_digit)
+//! * vec_of_chars.iter().filter(is_hex_digit)
+//!
+use crate::fmt::upper_hex_full;
+use std::borrow::Borrow;
+
+/// Convert a char into its unicode codepoint String, e.g.: `U+1F600`
+///
+/// Use .map(codepoint)
+///
+/// short for **format!("U+{:04X}", c as u32)**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec_to_strings: Vec<_> = "A😀é".chars().map(codepoint).collect();
+/// let expected = vec!["U+0041", "U+1F600", "U+00E9"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// let chars = vec!['\n'];
+/// let vec_to_strings: Vec<_> = chars.iter().map(codepoint).collect();
+/// assert_eq!(vec_to_strings, vec!["U+000A"]);
+/// ```
+pub fn codepoint<C: Borrow<char>>(c: C) -> String {
+    format!("U+{:04X}", *c.borrow() as u32)
+}
+
+/// Convert a char into its unicode codepoint followed by the escaped char, e.g.: `U+0041 'A'`
+///
+/// Use .map(codepoint_with_char)
+///
+/// short for **format!("U+{:04X} {:?}", c as u32, c)**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec_to_strings: Vec<_> = "A\t".chars().map(codepoint_with_char).collect();
+/// let expected = vec!["U+0041 'A'", "U+0009 '\\t'"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn codepoint_with_char<C: Borrow<char>>(c: C) -> String {
+    let c = *c.borrow();
+    format!("U+{:04X} {:?}", c as u32, c)
+}
+
+/// Convert a String or &str into the space separated unicode codepoints of its chars
+///
+/// Use .map(codepoints)
+///
+/// Makes invisible differences visible, e.g.: a precomposed é versus e followed by a combining accent.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let strings = vec!["\u{E9}", "e\u{301}"];
+/// let vec_to_strings: Vec<_> = strings.iter().map(codepoints).collect();
+/// let expected = vec!["U+00E9", "U+0065 U+0301"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn codepoints<S: AsRef<str>>(s: S) -> String {
+    s.as_ref()
+        .chars()
+        .map(codepoint)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Convert a String or &str into the space separated unicode codepoints of its chars,
+/// each followed by its UTF-8 encoding as upper case hexadecimal bytes in brackets
+///
+/// Use .map(codepoints_utf8)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = codepoints_utf8("Aé€");
+/// let expected = "U+0041 (41) U+00E9 (C3 A9) U+20AC (E2 82 AC)";
+/// assert_eq!(result, expected);
+/// ```
+pub fn codepoints_utf8<S: AsRef<str>>(s: S) -> String {
+    s.as_ref()
+        .chars()
+        .map(|c| {
+            let mut buffer = [0; 4];
+            let bytes = c.encode_utf8(&mut buffer).as_bytes();
+            let hex: Vec<_> = bytes.iter().map(upper_hex_full).collect();
+            format!("{} ({})", codepoint(c), hex.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns true if the char is an ASCII decimal digit, `0` to `9`
+///
+/// Use .filter(is_digit) after chars(), iter() or into_iter(), the char may be borrowed once or twice,
+/// unlike `char::is_ascii_digit` which cannot be used after iter() on a `Vec<char>`
+/// and `char::is_numeric` which cannot be used in .filter() at all.
+///
+/// short for **c.is_ascii_digit()**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let digits: String = "a1b2c3".chars().filter(is_digit).collect();
+/// assert_eq!(digits, "123");
+///
+/// let chars = vec!['7', 'x', '٣'];
+/// let by_ref: Vec<&char> = chars.iter().filter(is_digit).collect();
+/// let by_value: Vec<char> = chars.clone().into_iter().filter(is_digit).collect();
+/// assert_eq!(by_ref, vec![&'7']);
+/// assert_eq!(by_value, vec!['7']);
+/// ```
+pub fn is_digit<C: Borrow<char>>(c: &C) -> bool {
+    c.borrow().is_ascii_digit()
+}
+
+/// Returns true if the char is alphabetic, including letters outside ASCII such as `é` and `ж`
+///
+/// Use .filter(is_alpha) after chars(), iter() or into_iter()
+///
+/// short for **c.is_alphabetic()**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let letters: String = "é-1 ж!".chars().filter(is_alpha).collect();
+/// assert_eq!(letters, "éж");
+/// ```
+pub fn is_alpha<C: Borrow<char>>(c: &C) -> bool {
+    c.borrow().is_alphabetic()
+}
+
+/// Returns true if the char is an ASCII hexadecimal digit, `0` to `9`, `a` to `f` or `A` to `F`
+///
+/// Use .filter(is_hex_digit) after chars(), iter() or into_iter(), or .all(is_hex_digit) after iter()
+///
+/// short for **c.is_ascii_hexdigit()**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let hex: String = "0xDEAD_BEEF".chars().skip(2).filter(is_hex_digit).collect();
+/// assert_eq!(hex, "DEADBEEF");
+///
+/// let chars: Vec<char> = "C0ffee".chars().collect();
+/// assert!(chars.iter().all(is_hex_digit));
+/// ```
+pub fn is_hex_digit<C: Borrow<char>>(c: &C) -> bool {
+    c.borrow().is_ascii_hexdigit()
+}
+
+/// Returns true if the char is unicode whitespace, including new lines, tabs and the no-break space
+///
+/// Use .filter(is_whitespace_char) after chars(), iter() or into_iter(), or .any(is_whitespace_char) after iter()
+///
+/// short for **c.is_whitespace()**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let count = "a b\tc\n\u{A0}".chars().filter(is_whitespace_char).count();
+/// assert_eq!(count, 4);
+///
+/// let chars = vec!['x', ' '];
+/// assert!(chars.iter().any(is_whitespace_char));
+/// ```
+pub fn is_whitespace_char<C: Borrow<char>>(c: &C) -> bool {
+    c.borrow().is_whitespace()
+}
@@ -0,0 +1,525 @@
+//! Provides functions for formatting physical and engineering quantities
+//!
+//! ## The answer to the question:
+//!
+//! How do I convert a vector or iterator of numbers into a String of:
+//!
+//! * decibels from an amplitude ratio.
+//! * decibels from a power ratio.
+//! * degrees, minutes and seconds from decimal degrees.
+//! * decimals from raw fixed point (Qm.n) integers.
+//! * durations scaled to ns, µs, ms, s, minutes or hours.
+//!
+//! How do I print a month as a calendar grid, like `cal`, with some days highlighted?
+//!
+//! ## Use
+//!
+//! * iter.map(decibel)
+//! * iter.map(decibel_power_precision::<1, _>)
+//! * iter.map(dms)
+//! * iter.map(fixed_point::<15, _>)
+//! * iter.map(duration)
+//! * println!("{}", stopwatch)
+//! * calendar_month(2024, 2, &[14])
+//!
+use crate::align::{pad_to, Alignment};
+use crate::cast::{ToF64, ToI128};
+use crate::color::ANSI_RESET;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// Convert an amplitude (voltage, pressure) ratio into a decibel String with 2 decimal places
+///
+/// Use .map(decibel)
+///
+/// short for **format!("{:.2} dB", 20.0 * ratio.log10())**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![1.0, 10.0, 0.5];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(decibel).collect();
+/// let expected = vec!["0.00 dB", "20.00 dB", "-6.02 dB"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn decibel<F: ToF64>(ratio: F) -> String {
+    decibel_precision::<2, F>(ratio)
+}
+
+/// Convert an amplitude ratio into a decibel String with P decimal places
+///
+/// Use .map(decibel_precision::<1, _>) // 1 in this case is the precision
+///
+/// short for **format!("{:.1} dB", 20.0 * ratio.log10())**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![2.0_f32, 0.001];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(decibel_precision::<1, _>).collect();
+/// let expected = vec!["6.0 dB", "-60.0 dB"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn decibel_precision<const P: usize, F: ToF64>(ratio: F) -> String {
+    format!("{:.*} dB", P, 20.0 * ratio.to_f64().log10())
+}
+
+/// Convert a power ratio into a decibel String with 2 decimal places
+///
+/// Use .map(decibel_power)
+///
+/// short for **format!("{:.2} dB", 10.0 * ratio.log10())**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![0.5, 100.0];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(decibel_power).collect();
+/// let expected = vec!["-3.01 dB", "20.00 dB"];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn decibel_power<F: ToF64>(ratio: F) -> String {
+    decibel_power_precision::<2, F>(ratio)
+}
+
+/// Convert a power ratio into a decibel String with P decimal places
+///
+/// Use .map(decibel_power_precision::<3, _>) // 3 in this case is the precision
+///
+/// short for **format!("{:.3} dB", 10.0 * ratio.log10())**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = decibel_power_precision::<3, _>(2_u32);
+/// assert_eq!(result, "3.010 dB");
+/// ```
+pub fn decibel_power_precision<const P: usize, F: ToF64>(ratio: F) -> String {
+    format!("{:.*} dB", P, 10.0 * ratio.to_f64().log10())
+}
+
+/// Split decimal degrees into whole degrees, minutes and tenths of a second, rounding to the nearest tenth
+fn dms_parts(decimal_degrees: f64) -> (u64, u64, u64) {
+    let tenths = (decimal_degrees.abs() * 36_000.0).round() as u64;
+    (tenths / 36_000, tenths % 36_000 / 600, tenths % 600)
+}
+
+fn dms_unsigned(decimal_degrees: f64) -> String {
+    let (degrees, minutes, tenths) = dms_parts(decimal_degrees);
+    format!(
+        "{}°{:02}'{:02}.{}\"",
+        degrees,
+        minutes,
+        tenths / 10,
+        tenths % 10
+    )
+}
+
+/// Convert decimal degrees into a degrees, minutes and seconds String
+///
+/// Use .map(dms)
+///
+/// Seconds are rounded to one decimal place, negative values are prefixed with a minus sign.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec![51.507222, -0.1275];
+/// let vec_to_strings: Vec<_> = vec.into_iter().map(dms).collect();
+/// let expected = vec!["51°30'26.0\"", "-0°07'39.0\""];
+/// assert_eq!(vec_to_strings, expected);
+/// ```
+pub fn dms<F: ToF64>(decimal_degrees: F) -> String {
+    let decimal_degrees = decimal_degrees.to_f64();
+    let sign = if decimal_degrees < 0.0 && dms_parts(decimal_degrees) != (0, 0, 0) {
+        "-"
+    } else {
+        ""
+    };
+    format!("{}{}", sign, dms_unsigned(decimal_degrees))
+}
+
+/// Convert a latitude and longitude in decimal degrees into a degrees, minutes and seconds String
+/// with N/S and E/W hemisphere letters
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let result = lat_lon(51.507222, -0.1275);
+/// assert_eq!(result, "51°30'26.0\"N 0°07'39.0\"W");
+///
+/// let coordinates = vec![(-33.856667, 151.215278)];
+/// let vec_to_strings: Vec<_> = coordinates.into_iter().map(|(lat, lon)| lat_lon(lat, lon)).collect();
+/// assert_eq!(vec_to_strings, vec!["33°51'24.0\"S 151°12'55.0\"E"]);
+/// ```
+pub fn lat_lon<F: ToF64, G: ToF64>(lat: F, lon: G) -> String {
+    let (lat, lon) = (lat.to_f64(), lon.to_f64());
+    format!(
+        "{}{} {}{}",
+        dms_unsigned(lat),
+        if lat < 0.0 { 'S' } else { 'N' },
+        dms_unsigned(lon),
+        if lon < 0.0 { 'W' } else { 'E' }
+    )
+}
+
+/// Parse a degrees, minutes and seconds String, as produced by [`dms`], back into decimal degrees
+///
+/// Minutes, seconds and a trailing N/S/E/W hemisphere letter are optional, S and W are negative,
+/// bare decimal degrees such as "51.5" are accepted too.
+/// A leading - and a hemisphere letter together are ambiguous, so the String is rejected.
+/// Returns None if the String cannot be parsed, so can be used in .filter_map(parse_dms)
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let vec = vec!["51°30'26.0\"N", "0°07'39.0\"W", "-12°30'", "51.5", "-51°30'S", "not a coordinate"];
+/// let parsed: Vec<_> = vec.into_iter().filter_map(parse_dms).collect();
+/// let expected = [51.507222, -0.1275, -12.5, 51.5];
+///# assert_eq!(parsed.len(), expected.len());
+/// for (p, e) in parsed.iter().zip(expected) {
+///     assert!((p - e).abs() < 1e-6);
+/// }
+/// ```
+pub fn parse_dms<S: AsRef<str>>(s: S) -> Option<f64> {
+    let mut rest = s.as_ref().trim();
+    let mut sign = 1.0;
+    if let Some(stripped) = rest.strip_prefix('-') {
+        sign = -1.0;
+        rest = stripped;
+    }
+    if rest.starts_with(['-', '+']) {
+        return None;
+    }
+    if let Some(hemisphere) = rest.chars().last().filter(|c| c.is_ascii_alphabetic()) {
+        if sign < 0.0 {
+            return None;
+        }
+        match hemisphere.to_ascii_uppercase() {
+            'N' | 'E' => {}
+            'S' | 'W' => sign = -sign,
+            _ => return None,
+        }
+        rest = rest[..rest.len() - 1].trim_end();
+    }
+    if !rest.contains(['°', '\'', '"']) {
+        return Some(sign * rest.parse::<f64>().ok()?);
+    }
+    let mut value = 0.0;
+    for (symbol, divisor) in [('°', 1.0), ('\'', 60.0), ('"', 3600.0)] {
+        if rest.is_empty() {
+            break;
+        }
+        let (number, remainder) = rest.split_once(symbol)?;
+        value += number.trim().parse::<f64>().ok()? / divisor;
+        rest = remainder.trim_start();
+    }
+    if rest.is_empty() {
+        Some(sign * value)
+    } else {
+        None
+    }
+}
+
+/// Parse a latitude and longitude String, as produced by [`lat_lon`], back into decimal degrees
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let (lat, lon) = parse_lat_lon("51°30'26.0\"N 0°07'39.0\"W").unwrap();
+/// assert!((lat - 51.507222).abs() < 1e-6);
+/// assert!((lon + 0.1275).abs() < 1e-6);
+///
+/// assert_eq!(parse_lat_lon("51.5 -0.1275"), Some((51.5, -0.1275)));
+/// assert_eq!(parse_lat_lon("-51°30'S 0°07'W"), None);
+/// ```
+pub fn parse_lat_lon<S: AsRef<str>>(s: S) -> Option<(f64, f64)> {
+    let mut parts = s.as_ref().split_whitespace();
+    let lat = parse_dms(parts.next()?)?;
+    let lon = parse_dms(parts.next()?)?;
+    match parts.next() {
+        None => Some((lat, lon)),
+        Some(_) => None,
+    }
+}
+
+/// Convert a raw integer holding a fixed point (Qm.n) value with FRAC_BITS fractional bits into a decimal String
+///
+/// Use .map(fixed_point::<15, _>) // 15 in this case is the number of fractional bits
+///
+/// The number of decimal places is the smallest that distinguishes adjacent raw values,
+/// i.e. ceil(FRAC_BITS * log10(2)), and the last decimal place is rounded half up.
+/// The conversion uses integer arithmetic only, so there is no loss of precision for 64 bit values.
+///
+/// # Panics
+///
+/// If FRAC_BITS is greater than 64
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let registers: Vec<i16> = vec![0x4000, -0x4000, 0x0001, 0x7FFF];
+/// let vec_to_strings: Vec<_> = registers.into_iter().map(fixed_point::<15, _>).collect();
+/// let expected = vec!["0.50000", "-0.50000", "0.00003", "0.99997"];
+/// assert_eq!(vec_to_strings, expected);
+///
+/// // Q7.8
+/// assert_eq!(fixed_point::<8, _>(0x0180_u16), "1.500");
+/// ```
+pub fn fixed_point<const FRAC_BITS: u32, I: ToI128>(raw_int: I) -> String {
+    assert!(FRAC_BITS <= 64, "FRAC_BITS must be at most 64");
+    let raw = raw_int.to_i128();
+    let magnitude = raw.unsigned_abs();
+    let mask = (1u128 << FRAC_BITS) - 1;
+    let mut integer = magnitude >> FRAC_BITS;
+    let decimals = (FRAC_BITS as usize * 30103).div_ceil(100_000);
+    let mut remainder = magnitude & mask;
+    let mut fraction = 0u128;
+    for _ in 0..decimals {
+        remainder *= 10;
+        fraction = fraction * 10 + (remainder >> FRAC_BITS);
+        remainder &= mask;
+    }
+    if FRAC_BITS > 0 && remainder >= 1 << (FRAC_BITS - 1) {
+        fraction += 1;
+        if fraction == 10u128.pow(decimals as u32) {
+            fraction = 0;
+            integer += 1;
+        }
+    }
+    let sign = if raw < 0 { "-" } else { "" };
+    if decimals == 0 {
+        format!("{}{}", sign, integer)
+    } else {
+        format!("{}{}.{:0width$}", sign, integer, fraction, width = decimals)
+    }
+}
+
+/// Convert a [`std::time::Duration`] into a String scaled to the most readable unit
+///
+/// Use .map(duration)
+///
+/// Durations under a minute are shown in ns, µs, ms or s, rounded to 2 decimal places for µs and above
+/// before the unit is picked, longer durations are rounded to whole seconds and shown in minutes and seconds,
+/// or hours, minutes and seconds.
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::time::Duration;
+/// let durations = vec![
+///     Duration::from_nanos(15),
+///     Duration::from_nanos(12_345),
+///     Duration::from_micros(456_780),
+///     Duration::from_millis(1_234),
+///     Duration::from_secs(65),
+///     Duration::from_secs(3_723),
+/// ];
+/// let vec_to_strings: Vec<_> = durations.iter().map(duration).collect();
+/// let expected = vec!["15 ns", "12.35 µs", "456.78 ms", "1.23 s", "1m 05s", "1h 02m 03s"];
+/// assert_eq!(vec_to_strings, expected);
+///# assert_eq!(duration(Duration::from_nanos(999_999)), "1.00 ms");
+///# assert_eq!(duration(Duration::from_nanos(999_994)), "999.99 µs");
+///# assert_eq!(duration(Duration::from_nanos(999_995_000)), "1.00 s");
+///# assert_eq!(duration(Duration::from_millis(59_999)), "1m 00s");
+///# assert_eq!(duration(Duration::from_millis(65_900)), "1m 06s");
+///# assert_eq!(duration(Duration::from_millis(119_500)), "2m 00s");
+///# assert_eq!(duration(Duration::from_millis(119_499)), "1m 59s");
+///# assert_eq!(duration(Duration::from_millis(3_599_500)), "1h 00m 00s");
+/// ```
+pub fn duration<D: Borrow<Duration>>(d: D) -> String {
+    let nanos = d.borrow().as_nanos();
+    // round to 2 decimal places before picking the unit, so 999_999 ns is 1.00 ms rather than 1000.00 µs
+    let hundredths = |unit: u128| (nanos + unit / 200) / (unit / 100);
+    let (micros, millis, secs) = (
+        hundredths(1_000),
+        hundredths(1_000_000),
+        hundredths(1_000_000_000),
+    );
+    let whole_secs = (nanos + 500_000_000) / 1_000_000_000;
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if micros < 100_000 {
+        format!("{}.{:02} µs", micros / 100, micros % 100)
+    } else if millis < 100_000 {
+        format!("{}.{:02} ms", millis / 100, millis % 100)
+    } else if secs < 6_000 {
+        format!("{}.{:02} s", secs / 100, secs % 100)
+    } else if whole_secs < 3_600 {
+        format!("{}m {:02}s", whole_secs / 60, whole_secs % 60)
+    } else {
+        format!(
+            "{}h {:02}m {:02}s",
+            whole_secs / 3_600,
+            whole_secs % 3_600 / 60,
+            whole_secs % 60
+        )
+    }
+}
+
+/// Convert the time elapsed since an [`std::time::Instant`] into a String scaled like [`duration`]
+///
+/// short for **duration(instant.elapsed())**
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// use std::time::Instant;
+/// let start = Instant::now();
+/// let sum: u64 = (0..1000).sum();
+/// println!("summed to {} in {}", sum, elapsed_since(start));
+/// ```
+pub fn elapsed_since<I: Borrow<Instant>>(instant: I) -> String {
+    duration(instant.borrow().elapsed())
+}
+
+/// Measures elapsed time, its Display shows the elapsed time scaled and rounded like [`duration`]
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let mut stopwatch = Stopwatch::start();
+/// let sum: u64 = (0..1000).sum();
+/// println!("summed to {} in {}", sum, stopwatch);
+///
+/// let lap = stopwatch.restart();
+///# assert!(lap >= stopwatch.elapsed());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stopwatch {
+    start: Instant,
+}
+
+impl Stopwatch {
+    /// Start measuring from now
+    pub fn start() -> Self {
+        Stopwatch {
+            start: Instant::now(),
+        }
+    }
+
+    /// The time elapsed since the stopwatch was started
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Start measuring again from now, returns the time elapsed up to now
+    pub fn restart(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now - self.start;
+        self.start = now;
+        elapsed
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Stopwatch::start()
+    }
+}
+
+impl Display for Stopwatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&duration(self.elapsed()))
+    }
+}
+
+/// The English names of the months, January first
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The ANSI escape sequence for reverse video, used by `cal` to highlight today
+const ANSI_REVERSE: &str = "\x1b[7m";
+
+/// The number of days in month of year in the proleptic Gregorian calendar
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The day of the week of the first day of month in year, 0 for Sunday, by Sakamoto's method
+fn first_weekday(year: i32, month: u32) -> u32 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    let days = year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400);
+    (days + OFFSETS[month as usize - 1] + 1).rem_euclid(7) as u32
+}
+
+/// Convert a month into a calendar grid like the output of `cal`, weeks starting on Sunday,
+/// with the days in highlights shown in ANSI reverse video
+///
+/// The title is the month name and year centered over the 20 columns of the grid,
+/// trailing spaces are removed from each line and the String does not end with a new line.
+/// Days in highlights that are not in the month are ignored, pass `&[]` for no highlights.
+///
+/// # Panics
+///
+/// If month is not from 1 to 12
+///
+/// # Example
+/// ```
+/// use i2u::prelude::*;
+/// let expected = concat!(
+///     "   February 2024\n",
+///     "Su Mo Tu We Th Fr Sa\n",
+///     "             1  2  3\n",
+///     " 4  5  6  7  8  9 10\n",
+///     "11 12 13 14 15 16 17\n",
+///     "18 19 20 21 22 23 24\n",
+///     "25 26 27 28 29",
+/// );
+/// assert_eq!(calendar_month(2024, 2, &[]), expected);
+///
+/// let with_deadline = calendar_month(2024, 2, &[14]);
+/// assert!(with_deadline.contains("11 12 13 \x1b[7m14\x1b[0m 15 16 17"));
+///# assert!(calendar_month(2026, 10, &[1]).starts_with("    October 2026\nSu Mo Tu We Th Fr Sa\n            \x1b[7m 1\x1b[0m  2  3\n"));
+///# assert!(calendar_month(2023, 2, &[29, 30]).ends_with("26 27 28"));
+///# assert!(calendar_month(1900, 2, &[]).ends_with("25 26 27 28"));
+///# assert!(calendar_month(2000, 1, &[]).contains("\n                   1\n 2"));
+/// ```
+pub fn calendar_month(year: i32, month: u32, highlights: &[u32]) -> String {
+    assert!((1..=12).contains(&month), "month must be from 1 to 12");
+    let title = format!("{} {}", MONTH_NAMES[month as usize - 1], year);
+    let mut lines = vec![
+        pad_to(&title, 20, Alignment::Center).trim_end().to_string(),
+        "Su Mo Tu We Th Fr Sa".to_string(),
+    ];
+    let offset = first_weekday(year, month);
+    let days = days_in_month(year, month);
+    let mut week = " ".repeat(3 * offset as usize);
+    for day in 1..=days {
+        // the padding of the first week already includes the space before the first day
+        let column = (offset + day - 1) % 7;
+        if column != 0 {
+            if day > 1 {
+                week.push(' ');
+            }
+        } else if day > 1 {
+            lines.push(std::mem::take(&mut week));
+        }
+        if highlights.contains(&day) {
+            week.push_str(&format!("{}{:>2}{}", ANSI_REVERSE, day, ANSI_RESET));
+        } else {
+            week.push_str(&format!("{:>2}", day));
+        }
+    }
+    lines.push(week);
+    lines.join("\n")
+}
@@ -0,0 +1,118 @@
+//! Check that the integer formatters allocate exactly once per call, the Display and Debug formatters
+//! allocate the same as std, and fmt_with_buf reuses its buffer
+//!
+//! The global allocator counts the allocations and reallocations made by the current thread,
+//! so the tests can run in parallel.
+use i2u::prelude::fmt::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Call format and return its output with the number of allocations and reallocations it made
+fn allocations<F: FnOnce() -> String>(format: F) -> (String, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let formatted = format();
+    (formatted, ALLOCATIONS.with(Cell::get) - before)
+}
+
+macro_rules! assert_single_allocation {
+    ($($call:expr => $expected:expr),* $(,)?) => {
+        $(
+            let (formatted, count) = allocations(|| $call);
+            assert_eq!(formatted, $expected, "{}", stringify!($call));
+            assert_eq!(count, 1, "{} allocated {} times", stringify!($call), count);
+            assert_eq!(formatted.capacity(), formatted.len(), "{}", stringify!($call));
+        )*
+    };
+}
+
+/// Assert that each call returns the same String with the same number of allocations as the std equivalent
+macro_rules! assert_same_as_std {
+    ($($call:expr => $std:expr),* $(,)?) => {
+        $(
+            let (formatted, count) = allocations(|| $call);
+            let (expected, std_count) = allocations(|| $std);
+            assert_eq!(formatted, expected, "{}", stringify!($call));
+            assert_eq!(count, std_count, "{} allocated {} times, {} {} times", stringify!($call), count, stringify!($std), std_count);
+        )*
+    };
+}
+
+#[test]
+fn display_and_debug_formatters_call_std_directly() {
+    let vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let (open, item, close) = ("<", "a longer item", ">");
+    let angle_brackets = surround(open, close);
+    assert_same_as_std! {
+        to_string(1234567) => 1234567.to_string(),
+        to_string("a str") => "a str".to_string(),
+        debug(Some(1234567)) => format!("{:?}", Some(1234567)),
+        debug(&vec) => format!("{:?}", &vec),
+        debug_pretty((1, "two")) => format!("{:#?}", (1, "two")),
+        debug_zero_pad::<3, _>([7, 42]) => format!("{:03?}", [7, 42]),
+        angle_brackets(item) => format!("{}{}{}", open, item, close),
+    }
+}
+
+#[test]
+fn integer_formatters_allocate_once() {
+    assert_single_allocation! {
+        octal(u64::MAX) => "1777777777777777777777",
+        binary(u32::MAX) => "1".repeat(32),
+        binary_zero_pad::<16, _>(5) => "0000000000000101",
+        binary_pad::<16, _>(5) => "             101",
+        lower_hex_pad::<12, _>(0xC0FFEE) => "      c0ffee",
+        upper_hex_pad::<12, _>(0xC0FFEE) => "      C0FFEE",
+        lower_hex_zeropad::<12, _>(0xC0FFEE) => "000000c0ffee",
+        upper_hex_zeropad::<12, _>(0xC0FFEE) => "000000C0FFEE",
+        binary_full(1_u64) => format!("{:064b}", 1),
+        hex_full(u128::MAX) => "f".repeat(32),
+        upper_hex_full(0xBEEF_u32) => "0000BEEF",
+        signed_hex(-26) => "-0x1A",
+        signed_binary(-26) => "-11010",
+        hex_byte(0xAB_u8) => "ab",
+        hex_bytes(0xDEADBEEF_u32) => "DE AD BE EF",
+        hex_bytes_with(0x0102_u16, Endian::Little, "::") => "02::01",
+    }
+}
+
+#[test]
+fn fmt_with_buf_reuses_the_buffer() {
+    use std::fmt::Write;
+    let format = |n: u64| fmt_with_buf(n, |buf, n| write!(buf, "{:020}", n)).len();
+    // the first call grows the buffer to fit
+    assert_eq!(format(0), 20);
+    let before = ALLOCATIONS.with(Cell::get);
+    let lengths: usize = (1..=1000).map(format).sum();
+    let count = ALLOCATIONS.with(Cell::get) - before;
+    assert_eq!(lengths, 20_000);
+    assert_eq!(
+        count, 0,
+        "fmt_with_buf allocated {} times for 1000 items",
+        count
+    );
+}